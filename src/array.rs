@@ -0,0 +1,105 @@
+//! Allocation-free combinations of a length fixed at compile time.
+
+use advance;
+use count;
+use error;
+
+/// An `Iterator` yielding combinations of `K` elements from a sequence as arrays of references.
+///
+/// Created by `combinations_array`.
+pub struct ArrayCombinator<'a, T, const K: usize>
+    where T: 'a
+{
+    seq: &'a [T],
+    indices: [usize; K],
+    inited: bool,
+}
+
+impl<'a, T, const K: usize> Iterator for ArrayCombinator<'a, T, K> {
+    type Item = [&'a T; K];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if !self.inited {
+            self.inited = true;
+        } else if advance(&mut self.indices, self.seq.len()).is_none() {
+            return None;
+        }
+
+        let seq = self.seq;
+        Some(self.indices.map(|i| &seq[i]))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        count::size_hint(count::remaining(&self.indices, self.seq.len(), self.inited))
+    }
+}
+
+/// Returns an iterator yielding all combinations of length `K` from the sequence `seq`, in the
+/// same order as `combinations`.
+///
+/// Each combination is an array of references, so no allocation is needed and combinations can
+/// be destructured directly.
+///
+/// `K` is usually inferred from how the combinations are used. When it must be named, the
+/// element type has to be given too, as in `combinations_array::<K, _>(seq)`, since Rust does not
+/// allow a turbofish to name only some of a function's generic parameters.
+///
+/// # Panics
+/// When attempting to iterate over combination lengths longer than the original sequence.
+///
+/// # Examples
+///
+/// ```
+/// use combo::combinations_array;
+///
+/// let sequence = [1, 2, 3, 4];
+///
+/// let mut products = Vec::new();
+/// for [a, b] in combinations_array(&sequence) {
+///     products.push(a * b);
+/// }
+///
+/// assert_eq!(products, vec![2, 3, 4, 6, 8, 12]);
+///
+/// let triples = combinations_array::<3, _>(&sequence);
+/// assert_eq!(triples.count(), 4);
+/// ```
+pub fn combinations_array<const K: usize, T>(seq: &[T]) -> ArrayCombinator<'_, T, K> {
+    if let Err(err) = error::check_len(K, seq.len()) {
+        panic!("{}", err);
+    }
+
+    let mut indices = [0; K];
+    for (i, index) in indices.iter_mut().enumerate() {
+        *index = i;
+    }
+
+    ArrayCombinator {
+        seq,
+        indices,
+        inited: false,
+    }
+}
+
+#[cfg(test)]
+use combinations;
+
+#[test]
+fn matches_combinator_order() {
+    let sequence: Vec<u32> = (0..6).collect();
+
+    let combinator = combinations_array::<3, _>(&sequence);
+    assert_eq!(combinator.size_hint(), (20, Some(20)));
+
+    let arrays: Vec<Vec<&u32>> = combinator.map(|combo| combo.to_vec()).collect();
+    let vecs: Vec<Vec<&u32>> = combinations(&sequence[..], 3).owned().collect();
+
+    assert_eq!(arrays, vecs);
+}
+
+#[test]
+fn empty_combination_yielded_once() {
+    let sequence: Vec<u32> = Vec::new();
+
+    assert_eq!(combinations_array::<0, _>(&sequence).count(), 1);
+}
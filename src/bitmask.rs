@@ -0,0 +1,210 @@
+//! Combinations of small sequences represented as bitmasks, generated with Gosper's hack.
+
+use std::fmt::Debug;
+
+use error;
+
+mod sealed {
+    pub trait Sealed: Sized {
+        const BITS: usize;
+
+        /// The mask with the lowest `k` bits set.
+        fn lowest(k: usize) -> Self;
+
+        /// The next larger mask with the same number of set bits, if it fits.
+        fn gosper(self) -> Option<Self>;
+
+        /// Whether every set bit is below bit `n`.
+        fn below(self, n: usize) -> bool;
+
+        /// Clears the lowest set bit, returning its position.
+        fn pop_lowest(&mut self) -> Option<usize>;
+
+        /// The number of set bits.
+        fn count(self) -> usize;
+    }
+}
+
+use self::sealed::Sealed;
+
+/// An unsigned integer type usable as a bitmask of up to `BITS` elements.
+pub trait BitMask: Sealed + Copy + Eq + Debug {}
+
+macro_rules! impl_bitmask {
+    ($($t:ty),*) => {$(
+        impl Sealed for $t {
+            const BITS: usize = <$t>::BITS as usize;
+
+            fn lowest(k: usize) -> Self {
+                if k == <Self as Sealed>::BITS { <$t>::MAX } else { (1 << k) - 1 }
+            }
+
+            fn gosper(self) -> Option<Self> {
+                if self == 0 {
+                    return None;
+                }
+
+                // Move the lowest block of ones up by one place, and pack the rest of the block
+                // into the lowest bits
+                let lowest = self & self.wrapping_neg();
+                let carried = self.checked_add(lowest)?;
+                Some((((carried ^ self) >> 2) / lowest) | carried)
+            }
+
+            fn below(self, n: usize) -> bool {
+                n >= <Self as Sealed>::BITS || self >> n == 0
+            }
+
+            fn pop_lowest(&mut self) -> Option<usize> {
+                if *self == 0 {
+                    return None;
+                }
+
+                let i = self.trailing_zeros() as usize;
+                *self &= *self - 1;
+                Some(i)
+            }
+
+            fn count(self) -> usize {
+                self.count_ones() as usize
+            }
+        }
+
+        impl BitMask for $t {}
+    )*}
+}
+
+impl_bitmask!(u8, u16, u32, u64, u128, usize);
+
+/// An `Iterator` yielding every combination of length `k` from `n` elements as a bitmask in which
+/// bit `i` is set if element `i` is chosen.
+///
+/// Created by `bitmask_combinations`.
+#[derive(Debug, Clone)]
+pub struct BitmaskCombinations<M> {
+    next: Option<M>,
+    n: usize,
+}
+
+impl<M> Iterator for BitmaskCombinations<M>
+    where M: BitMask
+{
+    type Item = M;
+
+    fn next(&mut self) -> Option<M> {
+        let mask = self.next?;
+        let n = self.n;
+        self.next = mask.gosper().filter(|next| next.below(n));
+        Some(mask)
+    }
+}
+
+/// Returns an iterator yielding every combination of length `k` from `n` elements as a bitmask of
+/// type `M`, in increasing numeric order.
+///
+/// Masks can be mapped back onto a sequence with `mask_elements`.
+///
+/// # Panics
+/// When `k` is larger than `n`, or `n` is larger than the number of bits in `M`.
+///
+/// # Examples
+///
+/// ```
+/// use combo::bitmask_combinations;
+///
+/// let masks: Vec<u64> = bitmask_combinations::<u64>(4, 2).collect();
+/// assert_eq!(masks, vec![0b0011, 0b0101, 0b0110, 0b1001, 0b1010, 0b1100]);
+/// ```
+pub fn bitmask_combinations<M>(n: usize, k: usize) -> BitmaskCombinations<M>
+    where M: BitMask
+{
+    if let Err(err) = error::check_len(k, n) {
+        panic!("{}", err);
+    }
+    if n > M::BITS {
+        panic!("Sequence too long for bitmask ({} > {})", n, M::BITS);
+    }
+
+    BitmaskCombinations {
+        next: Some(M::lowest(k)),
+        n,
+    }
+}
+
+/// An `Iterator` yielding references to the elements of a sequence chosen by a bitmask.
+///
+/// Created by `mask_elements`.
+pub struct MaskElements<'a, T, M>
+    where T: 'a
+{
+    mask: M,
+    seq: &'a [T],
+}
+
+impl<'a, T, M> Iterator for MaskElements<'a, T, M>
+    where M: BitMask
+{
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        self.mask.pop_lowest().map(|i| &self.seq[i])
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.mask.count();
+        (len, Some(len))
+    }
+}
+
+impl<'a, T, M> ExactSizeIterator for MaskElements<'a, T, M> where M: BitMask {}
+
+/// Returns an iterator yielding references to the elements of `seq` whose bits are set in `mask`,
+/// in order.
+///
+/// # Panics
+/// When iterated, if `mask` has a bit set beyond the end of `seq`.
+///
+/// # Examples
+///
+/// ```
+/// use combo::mask_elements;
+///
+/// let sequence = ['a', 'b', 'c', 'd'];
+/// let chosen: String = mask_elements(0b1010u64, &sequence[..]).collect();
+/// assert_eq!(chosen, "bd");
+/// ```
+pub fn mask_elements<T, M>(mask: M, seq: &[T]) -> MaskElements<'_, T, M>
+    where M: BitMask
+{
+    MaskElements { mask, seq }
+}
+
+#[cfg(test)]
+use combinations;
+
+#[test]
+fn masks_match_combinations() {
+    let sequence: Vec<usize> = (0..7).collect();
+
+    let mut from_masks: Vec<Vec<usize>> = bitmask_combinations::<u64>(7, 3)
+                                              .map(|mask| mask_elements(mask, &sequence[..]))
+                                              .map(|elements| elements.cloned().collect())
+                                              .collect();
+    from_masks.sort();
+
+    let combos: Vec<Vec<usize>> = combinations(&sequence[..], 3).cloned().collect();
+    assert_eq!(from_masks, combos);
+}
+
+#[test]
+fn full_width_masks() {
+    assert_eq!(bitmask_combinations::<u64>(64, 1).count(), 64);
+    assert_eq!(bitmask_combinations::<u64>(64, 64).collect::<Vec<_>>(), vec![u64::MAX]);
+    assert_eq!(bitmask_combinations::<u128>(128, 127).count(), 128);
+    assert_eq!(bitmask_combinations::<u8>(8, 0).collect::<Vec<_>>(), vec![0]);
+    assert_eq!(bitmask_combinations::<u8>(0, 0).collect::<Vec<_>>(), vec![0]);
+
+    let masks: Vec<u32> = bitmask_combinations::<u32>(32, 30).collect();
+    assert_eq!(masks.len(), 496);
+    assert!(masks.windows(2).all(|pair| pair[0] < pair[1]));
+}
@@ -0,0 +1,258 @@
+//! Combinations in colexicographic order, where the combinations of a prefix of the sequence come
+//! first.
+
+use count;
+use error;
+use {CombinationIter, LendingItem, LendingIterator};
+
+/// A non-standard iterator yielding combinations of elements from a sequence in colexicographic
+/// order.
+///
+/// Created by `colex_combinations`.
+pub struct ColexCombinator<'a, T>
+    where T: 'a
+{
+    seq: &'a [T],
+    indices: Vec<usize>,
+    inited: bool,
+}
+
+impl<'a, 'b, T> ColexCombinator<'a, T> {
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&'b mut self) -> Option<CombinationIter<'a, 'b, T>> {
+        // First combination is special cased
+        if !self.inited {
+            self.inited = true;
+        } else {
+            advance_colex(&mut self.indices, self.seq.len())?;
+        }
+
+        Some(CombinationIter {
+            it: self.indices.iter(),
+            seq: self.seq,
+        })
+    }
+}
+
+/// Advances `indices` to the colexicographically next combination of `seq_len` elements,
+/// returning `None` (leaving `indices` untouched) if it already holds the last one.
+fn advance_colex(indices: &mut [usize], seq_len: usize) -> Option<()> {
+    let k = indices.len();
+
+    // Find the first index with room above it
+    let i = (0..k).find(|&i| indices[i] + 1 < if i + 1 == k { seq_len } else { indices[i + 1] })?;
+    indices[i] += 1;
+
+    // Reset child indices to their smallest values
+    for (j, index) in indices[..i].iter_mut().enumerate() {
+        *index = j;
+    }
+
+    Some(())
+}
+
+impl<'a, T> ColexCombinator<'a, T> {
+    /// Returns the indices into the sequence of the current combination, or of the combination the
+    /// next call to `next` will produce if it has not been called since construction or the last
+    /// `seek`.
+    pub fn indices(&self) -> &[usize] {
+        &self.indices
+    }
+
+    /// Returns the number of combinations still to be produced by `next`, or `None` if it does not
+    /// fit in a `u128`.
+    pub fn remaining(&self) -> Option<u128> {
+        let total = count::binomial(self.seq.len(), self.indices.len())?;
+        let produced = rank_colex(&self.indices) + if self.inited { 1 } else { 0 };
+        Some(total - produced)
+    }
+
+    /// Returns the position in colexicographic order of the combination most recently produced
+    /// by `next`, or `None` if `next` has not been called since construction or the last `seek`.
+    pub fn rank(&self) -> Option<u128> {
+        if self.inited {
+            Some(rank_colex(&self.indices))
+        } else {
+            None
+        }
+    }
+
+    /// Repositions the combinator so that the next call to `next` produces the combination at
+    /// position `rank` in colexicographic order.
+    ///
+    /// # Panics
+    /// When `rank` is not less than the total number of combinations.
+    pub fn seek(&mut self, rank: u128) {
+        let n = self.seq.len();
+        let k = self.indices.len();
+        match count::binomial(n, k) {
+            Some(total) if rank >= total => {
+                panic!("Combination rank out of range ({} >= {})", rank, total);
+            }
+            _ => {}
+        }
+
+        unrank_colex_into(rank, &mut self.indices);
+        self.inited = false;
+    }
+}
+
+impl<'a, 'b, T> LendingItem<'b> for ColexCombinator<'a, T> {
+    type Item = CombinationIter<'a, 'b, T>;
+}
+
+impl<'a, T> LendingIterator for ColexCombinator<'a, T> {
+    fn next(&mut self) -> Option<CombinationIter<'a, '_, T>> {
+        ColexCombinator::next(self)
+    }
+}
+
+/// Returns a (non-standard) iterator yielding all combinations of length `len` from the sequence
+/// `seq` in colexicographic order, that is ordered by their last index, then their second to last
+/// and so on.
+///
+/// The first C(`m`, `len`) combinations are exactly those of the first `m` elements, so extending
+/// the sequence only appends combinations, and ranks are independent of the sequence length.
+///
+/// # Panics
+/// When attempting to iterate over combination lengths longer than the original sequence.
+///
+/// # Examples
+///
+/// ```
+/// use combo::colex_combinations;
+///
+/// let sequence = ['a', 'b', 'c', 'd'];
+/// let mut combinator = colex_combinations(&sequence[..], 2);
+///
+/// let mut combinations = Vec::new();
+/// while let Some(combo) = combinator.next() {
+///     combinations.push(combo.collect::<String>());
+/// }
+///
+/// assert_eq!(combinations, vec!["ab", "ac", "bc", "ad", "bd", "cd"]);
+/// ```
+pub fn colex_combinations<T>(seq: &[T], len: usize) -> ColexCombinator<'_, T> {
+    if let Err(err) = error::check_len(len, seq.len()) {
+        panic!("{}", err);
+    }
+
+    ColexCombinator {
+        seq,
+        indices: (0..len).collect(),
+        inited: false,
+    }
+}
+
+/// Returns the position of the combination `indices` in colexicographic order, which does not
+/// depend on the length of the sequence.
+///
+/// # Panics
+/// When `indices` is not strictly increasing, or its rank does not fit in a `u128`.
+///
+/// # Examples
+///
+/// ```
+/// use combo::rank_colex;
+///
+/// assert_eq!(rank_colex(&[0, 1]), 0);
+/// assert_eq!(rank_colex(&[1, 3]), 4);
+/// ```
+pub fn rank_colex(indices: &[usize]) -> u128 {
+    let mut rank: u128 = 0;
+    for (i, &index) in indices.iter().enumerate() {
+        if i > 0 && index <= indices[i - 1] {
+            panic!("Invalid combination: {:?}", indices);
+        }
+
+        // Every combination whose elements from position `i` onwards agree with `indices`, but
+        // whose element at `i` is smaller, comes first
+        rank = count::binomial(index, i + 1)
+                   .and_then(|block| rank.checked_add(block))
+                   .expect("Combination rank overflows u128");
+    }
+
+    rank
+}
+
+/// Returns the combination of length `k` at position `rank` in colexicographic order.
+///
+/// # Examples
+///
+/// ```
+/// use combo::unrank_colex;
+///
+/// assert_eq!(unrank_colex(4, 2), vec![1, 3]);
+/// ```
+pub fn unrank_colex(rank: u128, k: usize) -> Vec<usize> {
+    let mut indices = vec![0; k];
+    unrank_colex_into(rank, &mut indices);
+    indices
+}
+
+/// Writes the combination at position `rank` in colexicographic order into `indices`.
+fn unrank_colex_into(mut rank: u128, indices: &mut [usize]) {
+    for i in (0..indices.len()).rev() {
+        // Find the largest index whose block of smaller combinations still fits within the rank,
+        // first doubling an upper bound then bisecting
+        let fits = |index: usize| count::binomial(index, i + 1).is_some_and(|block| block <= rank);
+
+        let mut lo = i;
+        let mut hi = i + 1;
+        while fits(hi) {
+            lo = hi;
+            hi = hi.checked_mul(2).expect("Combination index overflows usize");
+        }
+        while hi - lo > 1 {
+            let mid = lo + (hi - lo) / 2;
+            if fits(mid) {
+                lo = mid;
+            } else {
+                hi = mid;
+            }
+        }
+
+        rank -= count::binomial(lo, i + 1).unwrap();
+        indices[i] = lo;
+    }
+}
+
+#[test]
+fn colex_order_sorts_by_reversed_indices() {
+    let sequence: Vec<usize> = (0..7).collect();
+
+    let mut expected: Vec<Vec<usize>> = ::combinations(&sequence[..], 3).cloned().collect();
+    expected.sort_by_key(|combo| combo.iter().rev().cloned().collect::<Vec<_>>());
+
+    let mut combinator = colex_combinations(&sequence[..], 3);
+    let mut generated = Vec::new();
+    while let Some(combo) = combinator.next() {
+        let combo: Vec<usize> = combo.cloned().collect();
+        assert_eq!(rank_colex(&combo), generated.len() as u128);
+        assert_eq!(unrank_colex(generated.len() as u128, 3), combo);
+        generated.push(combo);
+        assert_eq!(combinator.remaining(), Some((35 - generated.len()) as u128));
+    }
+    assert_eq!(generated, expected);
+
+    // Prefixes of the sequence enumerate a prefix of the combinations
+    let mut combinator = colex_combinations(&sequence[..5], 3);
+    let mut prefix = Vec::new();
+    while let Some(combo) = combinator.next() {
+        prefix.push(combo.cloned().collect::<Vec<_>>());
+    }
+    assert_eq!(&prefix[..], &generated[..10]);
+}
+
+#[test]
+fn seek_and_unrank_large_ranks() {
+    let sequence: Vec<u32> = (0..10).collect();
+
+    let mut combinator = colex_combinations(&sequence[..], 4);
+    combinator.seek(100);
+    assert_eq!(combinator.next().unwrap().count(), 4);
+    assert_eq!(combinator.rank(), Some(100));
+
+    let indices = unrank_colex(1 << 100, 3);
+    assert_eq!(rank_colex(&indices), 1 << 100);
+}
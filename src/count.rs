@@ -0,0 +1,139 @@
+//! Counting combinations with the binomial coefficient.
+
+#[cfg(feature = "num-bigint")]
+use num_bigint::BigUint;
+use std::convert::TryFrom;
+
+/// Returns the binomial coefficient C(`n`, `k`), the number of combinations of length `k` from a
+/// sequence of length `n`, or `None` if it does not fit in a `u128`.
+///
+/// # Examples
+///
+/// ```
+/// use combo::binomial;
+///
+/// assert_eq!(binomial(5, 3), Some(10));
+/// assert_eq!(binomial(3, 5), Some(0));
+/// assert_eq!(binomial(200, 100), None);
+/// ```
+pub fn binomial(n: usize, k: usize) -> Option<u128> {
+    if k > n {
+        return Some(0);
+    }
+
+    let k = k.min(n - k);
+    let mut c: u128 = 1;
+    for i in 0..k {
+        // C(n, i + 1) = C(n, i) * (n - i) / (i + 1), with the common factor of C(n, i) and i + 1
+        // divided out first so the product only overflows if the result does
+        let (num, den) = ((n - i) as u128, (i + 1) as u128);
+        let g = gcd(c, den);
+        c = (c / g).checked_mul(num / (den / g))?;
+    }
+
+    Some(c)
+}
+
+/// Returns the multiset coefficient C(`n` + `k` - 1, `k`), the number of combinations with
+/// replacement of length `k` from a sequence of length `n`, or `None` if it does not fit in a
+/// `u128`.
+///
+/// # Examples
+///
+/// ```
+/// use combo::multichoose;
+///
+/// assert_eq!(multichoose(3, 2), Some(6));
+/// assert_eq!(multichoose(0, 0), Some(1));
+/// ```
+pub fn multichoose(n: usize, k: usize) -> Option<u128> {
+    if n == 0 {
+        return Some(if k == 0 { 1 } else { 0 });
+    }
+
+    binomial(n + k - 1, k)
+}
+
+/// Returns the binomial coefficient C(`n`, `k`) as an arbitrary precision integer.
+#[cfg(feature = "num-bigint")]
+pub fn binomial_big(n: usize, k: usize) -> BigUint {
+    if k > n {
+        return BigUint::from(0u32);
+    }
+
+    let k = k.min(n - k);
+    let mut c = BigUint::from(1u32);
+    for i in 0..k {
+        c = c * (n - i) / (i + 1);
+    }
+
+    c
+}
+
+fn gcd(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        let t = a % b;
+        a = b;
+        b = t;
+    }
+    a
+}
+
+/// Returns the number of combinations of `n` elements still to be produced by a cursor at
+/// `indices`, or `None` if it does not fit in a `u128`.
+///
+/// If `inited` is false, `indices` is the next combination to be produced rather than the last.
+pub(crate) fn remaining(indices: &[usize], n: usize, inited: bool) -> Option<u128> {
+    let k = indices.len();
+
+    // Combinations after `indices` either share its first `i` elements and have a larger element
+    // at position `i`, or are beyond it entirely, giving one term per position
+    let mut count: u128 = if inited { 0 } else { 1 };
+    for (i, &index) in indices.iter().enumerate() {
+        count = count.checked_add(binomial(n - 1 - index, k - i)?)?;
+    }
+
+    Some(count)
+}
+
+/// Arbitrary precision counterpart to `remaining`.
+#[cfg(feature = "num-bigint")]
+pub(crate) fn remaining_big(indices: &[usize], n: usize, inited: bool) -> BigUint {
+    let k = indices.len();
+
+    let mut count = BigUint::from(if inited { 0u32 } else { 1u32 });
+    for (i, &index) in indices.iter().enumerate() {
+        count += binomial_big(n - 1 - index, k - i);
+    }
+
+    count
+}
+
+/// Converts a remaining count into an `Iterator::size_hint`.
+pub(crate) fn size_hint(remaining: Option<u128>) -> (usize, Option<usize>) {
+    match remaining.and_then(|r| usize::try_from(r).ok()) {
+        Some(n) => (n, Some(n)),
+        None => (usize::MAX, None),
+    }
+}
+
+#[test]
+fn binomial_matches_pascals_triangle() {
+    for n in 1..60 {
+        for k in 1..n {
+            assert_eq!(binomial(n, k),
+                       Some(binomial(n - 1, k - 1).unwrap() + binomial(n - 1, k).unwrap()));
+        }
+    }
+
+    assert_eq!(binomial(130, 65), Some(95067625827960698145584333020095113100));
+    assert_eq!(binomial(132, 66), None);
+}
+
+#[cfg(all(test, feature = "num-bigint"))]
+#[test]
+fn big_binomial_matches_checked() {
+    assert_eq!(binomial_big(130, 65), BigUint::from(binomial(130, 65).unwrap()));
+    assert_eq!(binomial_big(200, 100).to_string(),
+               "90548514656103281165404177077484163874504589675413336841320");
+}
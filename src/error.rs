@@ -0,0 +1,46 @@
+//! Errors reported when constructing combinators.
+
+use std::error::Error;
+use std::fmt;
+
+/// An error describing why a combinator could not be constructed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ComboError {
+    /// The requested combination length is longer than the sequence.
+    LengthTooLong {
+        /// The requested combination length.
+        len: usize,
+        /// The length of the sequence.
+        seq_len: usize,
+    },
+    /// A saved combinator state is inconsistent with itself or with the sequence it was resumed
+    /// against.
+    InvalidState {
+        /// Which check the state failed.
+        reason: &'static str,
+    },
+}
+
+impl fmt::Display for ComboError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ComboError::LengthTooLong { len, seq_len } => {
+                write!(f, "Combination length longer than sequence ({} > {})", len, seq_len)
+            }
+            ComboError::InvalidState { reason } => {
+                write!(f, "Invalid combinator state: {}", reason)
+            }
+        }
+    }
+}
+
+impl Error for ComboError {}
+
+/// Checks that combinations of length `len` can be taken from a sequence of length `seq_len`.
+pub(crate) fn check_len(len: usize, seq_len: usize) -> Result<(), ComboError> {
+    if len > seq_len {
+        Err(ComboError::LengthTooLong { len, seq_len })
+    } else {
+        Ok(())
+    }
+}
@@ -0,0 +1,94 @@
+//! Combinations of indices `0..n`, without a backing sequence.
+
+use count;
+use error;
+use {advance, LendingItem, LendingIterator};
+
+/// A non-standard iterator yielding combinations of the indices `0..n` as slices.
+///
+/// Created by `index_combinations`.
+pub struct IndexCombinator {
+    n: usize,
+    indices: Vec<usize>,
+    inited: bool,
+}
+
+impl IndexCombinator {
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> Option<&[usize]> {
+        // First combination is special cased
+        if !self.inited {
+            self.inited = true;
+        } else {
+            advance(&mut self.indices, self.n)?;
+        }
+
+        Some(&self.indices)
+    }
+
+    /// Returns the number of combinations still to be produced by `next`, or `None` if it does not
+    /// fit in a `u128`.
+    pub fn remaining(&self) -> Option<u128> {
+        count::remaining(&self.indices, self.n, self.inited)
+    }
+}
+
+impl<'b> LendingItem<'b> for IndexCombinator {
+    type Item = &'b [usize];
+}
+
+impl LendingIterator for IndexCombinator {
+    fn next(&mut self) -> Option<&[usize]> {
+        IndexCombinator::next(self)
+    }
+}
+
+/// Returns a (non-standard) iterator yielding all combinations of length `len` from the indices
+/// `0..n`, in the same order as `combinations`.
+///
+/// Only the `len` indices of the current combination are stored, so `n` may be arbitrarily
+/// large.
+///
+/// # Panics
+/// When attempting to iterate over combination lengths longer than `n`.
+///
+/// # Examples
+///
+/// ```
+/// use combo::index_combinations;
+///
+/// let mut combinator = index_combinations(1_000_000_000, 2);
+///
+/// assert_eq!(combinator.next(), Some(&[0, 1][..]));
+/// assert_eq!(combinator.next(), Some(&[0, 2][..]));
+/// ```
+pub fn index_combinations(n: usize, len: usize) -> IndexCombinator {
+    if let Err(err) = error::check_len(len, n) {
+        panic!("{}", err);
+    }
+
+    IndexCombinator {
+        n,
+        indices: (0..len).collect(),
+        inited: false,
+    }
+}
+
+#[cfg(test)]
+use combinations;
+
+#[test]
+fn matches_combinations_of_range() {
+    let sequence: Vec<usize> = (0..8).collect();
+
+    let expected: Vec<Vec<usize>> = combinations(&sequence[..], 3).cloned().collect();
+
+    let mut combinator = index_combinations(8, 3);
+    let mut generated = Vec::new();
+    while let Some(indices) = combinator.next() {
+        generated.push(indices.to_vec());
+    }
+
+    assert_eq!(generated, expected);
+    assert_eq!(combinator.remaining(), Some(0));
+}
@@ -0,0 +1,157 @@
+//! Combinations of the items of an arbitrary iterator, buffered lazily.
+
+use {advance, CombinationIter, LendingItem, LendingIterator};
+
+/// Extension trait adding combination methods to every `Iterator`.
+pub trait IteratorExt: Iterator + Sized {
+    /// Returns a (non-standard) iterator yielding all combinations of length `len` from the items
+    /// of this iterator, in the same order as `combinations` would over the collected items.
+    ///
+    /// Items are only pulled from the underlying iterator when a combination needs them, and
+    /// buffered so they can appear in later combinations. If there are fewer than `len` items,
+    /// no combinations are produced.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use combo::IteratorExt;
+    ///
+    /// let words = "red green blue".split(' ');
+    /// let mut combinator = words.combinations(2);
+    ///
+    /// let mut pairs = Vec::new();
+    /// while let Some(combo) = combinator.next() {
+    ///     pairs.push(combo.cloned().collect::<Vec<_>>().join("+"));
+    /// }
+    ///
+    /// assert_eq!(pairs, vec!["red+green", "red+blue", "green+blue"]);
+    /// ```
+    fn combinations(self, len: usize) -> LazyCombinator<Self> {
+        LazyCombinator {
+            iter: self,
+            buffer: Vec::new(),
+            exhausted: false,
+            indices: (0..len).collect(),
+            inited: false,
+        }
+    }
+}
+
+impl<I> IteratorExt for I where I: Iterator {}
+
+/// A non-standard iterator yielding combinations of the items of an iterator.
+///
+/// Created by `IteratorExt::combinations`.
+pub struct LazyCombinator<I>
+    where I: Iterator
+{
+    iter: I,
+    buffer: Vec<I::Item>,
+    exhausted: bool,
+    indices: Vec<usize>,
+    inited: bool,
+}
+
+impl<I> LazyCombinator<I>
+    where I: Iterator
+{
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> Option<CombinationIter<'_, '_, I::Item>> {
+        let k = self.indices.len();
+
+        // First combination is special cased
+        if !self.inited {
+            self.inited = true;
+            while self.buffer.len() < k && self.pull() {}
+            if self.buffer.len() < k {
+                return None;
+            }
+        } else {
+            // The last index can only carry into its parents once every item is buffered, at
+            // which point the buffer length is the true sequence length
+            if k > 0 && self.indices[k - 1] + 1 == self.buffer.len() {
+                self.pull();
+            }
+
+            if self.buffer.len() < k {
+                return None;
+            }
+            advance(&mut self.indices, self.buffer.len())?;
+        }
+
+        Some(CombinationIter {
+            it: self.indices.iter(),
+            seq: &self.buffer,
+        })
+    }
+
+    /// Buffers the next item from the underlying iterator, returning `false` if there are none.
+    fn pull(&mut self) -> bool {
+        if self.exhausted {
+            return false;
+        }
+
+        match self.iter.next() {
+            Some(item) => {
+                self.buffer.push(item);
+                true
+            }
+            None => {
+                self.exhausted = true;
+                false
+            }
+        }
+    }
+}
+
+impl<'b, I> LendingItem<'b> for LazyCombinator<I>
+    where I: Iterator
+{
+    type Item = CombinationIter<'b, 'b, I::Item>;
+}
+
+impl<I> LendingIterator for LazyCombinator<I>
+    where I: Iterator
+{
+    fn next(&mut self) -> Option<CombinationIter<'_, '_, I::Item>> {
+        LazyCombinator::next(self)
+    }
+}
+
+#[cfg(test)]
+use std::cell::Cell;
+
+#[test]
+fn matches_combinations_order() {
+    let sequence: Vec<u32> = (0..7).collect();
+
+    for k in 0..9 {
+        let expected: Vec<Vec<u32>> = if k <= 7 {
+            ::combinations(&sequence[..], k).cloned().collect()
+        } else {
+            Vec::new()
+        };
+
+        let mut combinator = (0..7u32).combinations(k);
+        let mut generated = Vec::new();
+        while let Some(combo) = combinator.next() {
+            generated.push(combo.cloned().collect::<Vec<_>>());
+        }
+
+        assert_eq!(generated, expected);
+        assert!(combinator.next().is_none());
+    }
+}
+
+#[test]
+fn pulls_items_only_when_needed() {
+    let pulled = Cell::new(0);
+    let mut combinator = (0..100).inspect(|_| pulled.set(pulled.get() + 1)).combinations(3);
+
+    combinator.next();
+    assert_eq!(pulled.get(), 3);
+
+    combinator.next();
+    combinator.next();
+    assert_eq!(pulled.get(), 5);
+}
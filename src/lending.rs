@@ -0,0 +1,239 @@
+//! A lending iterator abstraction for sources, like `Combinator`, whose items borrow from the
+//! source itself.
+
+mod sealed {
+    pub trait Sealed {}
+
+    pub struct Bounds<T: ?Sized>(T);
+
+    impl<T: ?Sized> Sealed for Bounds<T> {}
+}
+
+use self::sealed::{Bounds, Sealed};
+
+/// The type of item yielded by a `LendingIterator` for a borrow of lifetime `'b`.
+///
+/// The defaulted parameter restricts `'b` to lifetimes for which `&'b Self` is valid, which lets
+/// `for<'b>` bounds over this trait hold for non-`'static` iterators. It should never be named
+/// explicitly.
+pub trait LendingItem<'b, Bound: Sealed = Bounds<&'b Self>> {
+    type Item;
+}
+
+/// Shorthand for the item type of the lending iterator `I` borrowed for `'b`.
+pub type Item<'b, I> = <I as LendingItem<'b>>::Item;
+
+/// An iterator whose items may borrow from the iterator itself.
+///
+/// Each item must be dropped before the next one can be requested, so the standard `Iterator`
+/// trait cannot be used.
+pub trait LendingIterator: for<'b> LendingItem<'b> {
+    /// Advances the iterator and returns the next item.
+    fn next(&mut self) -> Option<Item<'_, Self>>;
+
+    /// Creates a standard `Iterator` yielding the results of calling `f` on each item.
+    fn map<B, F>(self, f: F) -> Map<Self, F>
+        where Self: Sized,
+              F: FnMut(Item<'_, Self>) -> B
+    {
+        Map { iter: self, f }
+    }
+
+    /// Creates a lending iterator yielding only the items for which `predicate` returns `true`.
+    fn filter<P>(self, predicate: P) -> Filter<Self, P>
+        where Self: Sized,
+              P: FnMut(&Item<'_, Self>) -> bool
+    {
+        Filter {
+            iter: self,
+            predicate,
+        }
+    }
+
+    /// Creates a lending iterator yielding at most the first `n` items.
+    fn take(self, n: usize) -> Take<Self>
+        where Self: Sized
+    {
+        Take { iter: self, n }
+    }
+
+    /// Creates a lending iterator skipping the first `n` items.
+    fn skip(self, n: usize) -> Skip<Self>
+        where Self: Sized
+    {
+        Skip { iter: self, n }
+    }
+
+    /// Calls `f` on each remaining item.
+    fn for_each<F>(mut self, mut f: F)
+        where Self: Sized,
+              F: FnMut(Item<'_, Self>)
+    {
+        while let Some(item) = self.next() {
+            f(item);
+        }
+    }
+
+    /// Consumes the iterator, returning the number of remaining items.
+    fn count(mut self) -> usize
+        where Self: Sized
+    {
+        let mut count = 0;
+        while self.next().is_some() {
+            count += 1;
+        }
+        count
+    }
+}
+
+impl<'b, I> LendingItem<'b> for &mut I
+    where I: LendingIterator + ?Sized
+{
+    type Item = Item<'b, I>;
+}
+
+impl<I> LendingIterator for &mut I
+    where I: LendingIterator + ?Sized
+{
+    fn next(&mut self) -> Option<Item<'_, Self>> {
+        (**self).next()
+    }
+}
+
+/// A standard `Iterator` mapping the items of a `LendingIterator` to owned values.
+///
+/// Created by `LendingIterator::map`.
+pub struct Map<I, F> {
+    iter: I,
+    f: F,
+}
+
+impl<B, I, F> Iterator for Map<I, F>
+    where I: LendingIterator,
+          F: FnMut(Item<'_, I>) -> B
+{
+    type Item = B;
+
+    fn next(&mut self) -> Option<B> {
+        self.iter.next().map(&mut self.f)
+    }
+}
+
+/// A lending iterator yielding the items of another which satisfy a predicate.
+///
+/// Created by `LendingIterator::filter`.
+pub struct Filter<I, P> {
+    iter: I,
+    predicate: P,
+}
+
+impl<'b, I, P> LendingItem<'b> for Filter<I, P>
+    where I: LendingIterator
+{
+    type Item = Item<'b, I>;
+}
+
+impl<I, P> LendingIterator for Filter<I, P>
+    where I: LendingIterator,
+          P: FnMut(&Item<'_, I>) -> bool
+{
+    fn next(&mut self) -> Option<Item<'_, Self>> {
+        let iter: *mut I = &mut self.iter;
+        loop {
+            // The borrow checker cannot yet see that a rejected item's borrow ends with the loop
+            // iteration, so the iterator is reborrowed through a raw pointer. Each item is dropped
+            // before the next call, so no two borrows of `iter` are ever live at once.
+            let item = unsafe { &mut *iter }.next()?;
+            if (self.predicate)(&item) {
+                return Some(item);
+            }
+        }
+    }
+}
+
+/// A lending iterator yielding at most a fixed number of items from another.
+///
+/// Created by `LendingIterator::take`.
+pub struct Take<I> {
+    iter: I,
+    n: usize,
+}
+
+impl<'b, I> LendingItem<'b> for Take<I>
+    where I: LendingIterator
+{
+    type Item = Item<'b, I>;
+}
+
+impl<I> LendingIterator for Take<I>
+    where I: LendingIterator
+{
+    fn next(&mut self) -> Option<Item<'_, Self>> {
+        if self.n == 0 {
+            return None;
+        }
+
+        self.n -= 1;
+        self.iter.next()
+    }
+}
+
+/// A lending iterator skipping a fixed number of items from another.
+///
+/// Created by `LendingIterator::skip`.
+pub struct Skip<I> {
+    iter: I,
+    n: usize,
+}
+
+impl<'b, I> LendingItem<'b> for Skip<I>
+    where I: LendingIterator
+{
+    type Item = Item<'b, I>;
+}
+
+impl<I> LendingIterator for Skip<I>
+    where I: LendingIterator
+{
+    fn next(&mut self) -> Option<Item<'_, Self>> {
+        while self.n > 0 {
+            self.n -= 1;
+            self.iter.next()?;
+        }
+
+        self.iter.next()
+    }
+}
+
+#[cfg(test)]
+use combinations;
+
+#[cfg(test)]
+fn sums<'a, I>(iter: I) -> Vec<u32>
+    where I: LendingIterator,
+          for<'b> Item<'b, I>: Iterator<Item = &'a u32>
+{
+    iter.map(|combo| combo.sum()).collect()
+}
+
+#[test]
+fn generic_over_combinator() {
+    let sequence: Vec<u32> = (0..4).collect();
+
+    assert_eq!(sums(combinations(&sequence[..], 2)), vec![1, 2, 3, 3, 4, 5]);
+}
+
+#[test]
+fn adaptors_compose() {
+    let sequence: Vec<u32> = (0..5).collect();
+
+    let combinator = combinations(&sequence[..], 3);
+    let filtered = combinator.filter(|combo| combo.clone().any(|&x| x == 4));
+    assert_eq!(sums(filtered.skip(1).take(3)), vec![6, 7, 7]);
+
+    assert_eq!(combinations(&sequence[..], 3).count(), 10);
+
+    let mut total = 0;
+    combinations(&sequence[..], 2).for_each(|combo| total += combo.count());
+    assert_eq!(total, 20);
+}
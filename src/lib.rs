@@ -1,6 +1,76 @@
 //! Small crate containing functionality for iterating over combinations of a sequence.
 //!
-//! See documentation of `combinations` function for usage details.
+//! See documentation of `combinations` function for usage details. Combinators also implement
+//! the `LendingIterator` trait, so they can be consumed by code generic over any combination
+//! source, and can be converted into standard iterators over owned combinations with `owned` and
+//! `cloned`.
+//!
+//! With the `rayon` feature enabled, `par_combinations` enumerates combinations in parallel. With
+//! the `rand` feature enabled, `random_combination` and `sample_combinations` draw combinations
+//! uniformly at random. With the `serde` feature enabled, a `Combinator` can be checkpointed with
+//! `state` and later continued with `resume`.
+
+#[cfg(feature = "num-bigint")]
+extern crate num_bigint;
+#[cfg(feature = "rand")]
+extern crate rand;
+#[cfg(feature = "rayon")]
+extern crate rayon;
+#[cfg(feature = "serde")]
+extern crate serde;
+#[cfg(all(test, feature = "serde"))]
+extern crate serde_json;
+
+mod array;
+mod bitmask;
+mod colex;
+mod count;
+mod error;
+mod index;
+mod lazy;
+mod lending;
+mod mutable;
+mod owned;
+#[cfg(feature = "rayon")]
+mod par;
+mod permutation;
+mod power_set;
+mod rank;
+mod replacement;
+mod revolving_door;
+#[cfg(feature = "rand")]
+mod sample;
+mod shuffled;
+mod split;
+#[cfg(feature = "serde")]
+mod state;
+mod tuples;
+
+pub use array::{combinations_array, ArrayCombinator};
+pub use bitmask::{bitmask_combinations, mask_elements, BitMask, BitmaskCombinations, MaskElements};
+pub use colex::{colex_combinations, rank_colex, unrank_colex, ColexCombinator};
+pub use count::{binomial, multichoose};
+pub use error::ComboError;
+pub use index::{index_combinations, IndexCombinator};
+pub use lazy::{IteratorExt, LazyCombinator};
+#[cfg(feature = "num-bigint")]
+pub use count::binomial_big;
+pub use lending::{Filter, Item, LendingItem, LendingIterator, Map, Skip, Take};
+pub use mutable::{combinations_mut, CombinationIterMut, CombinatorMut};
+pub use owned::{Cloned, Owned};
+#[cfg(feature = "rayon")]
+pub use par::{par_combinations, ParCombinations};
+pub use permutation::{k_permutations, permutations, Permutator};
+pub use power_set::{power_set, PowerSet, PowerSetOrder};
+pub use rank::{rank_combination, unrank_combination};
+pub use replacement::{combinations_with_replacement, CombinatorWithReplacement};
+pub use revolving_door::{revolving_door, RevolvingDoor};
+#[cfg(feature = "rand")]
+pub use sample::{random_combination, sample_combinations, Combination};
+pub use shuffled::{shuffled_combinations, ShuffledCombinator};
+#[cfg(feature = "serde")]
+pub use state::CombinatorState;
+pub use tuples::{pairs, triples, Pairs, Triples};
 
 #[cfg(test)]
 use std::collections::BTreeSet;
@@ -12,45 +82,346 @@ pub struct Combinator<'a, T>
     seq: &'a [T],
     indices: Vec<usize>,
     inited: bool,
+    // First position of `indices` changed by the last call to `next`
+    changed: usize,
+    // Number of combinations still to be produced, when bounded by a split
+    left: Option<u128>,
+    // Rank of the first combination in the range, when bounded by a split
+    start: u128,
 }
 
 impl<'a, 'b, T> Combinator<'a, T> {
+    #[allow(clippy::should_implement_trait)]
     pub fn next(&'b mut self) -> Option<CombinationIter<'a, 'b, T>> {
-        let seq_len = self.seq.len();
-        let ref mut indices = self.indices;
-        let k = indices.len();
+        if self.left == Some(0) {
+            return None;
+        }
 
         // First permutation is special cased
         if !self.inited {
             self.inited = true;
+            self.changed = 0;
+        } else {
+            self.changed = advance(&mut self.indices, self.seq.len())?;
+        }
+
+        if let Some(ref mut left) = self.left {
+            *left -= 1;
+        }
+
+        Some(CombinationIter {
+            it: self.indices.iter(),
+            seq: self.seq,
+        })
+    }
+
+    /// Steps back to the previous combination in lexicographic order, the reverse of `next`.
+    ///
+    /// If `next` has not been called since construction or the last seek, the combination it would
+    /// have produced is produced instead, so `seek_last` followed by repeated calls to `prev`
+    /// enumerates every combination in reverse. A combinator from `split_at` or `chunks` stays
+    /// within its range in both directions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use combo::combinations;
+    ///
+    /// let sequence: Vec<u32> = (0..4).collect();
+    /// let mut combinator = combinations(&sequence[..], 3);
+    /// combinator.seek_last();
+    ///
+    /// let mut reversed = Vec::new();
+    /// while let Some(combo) = combinator.prev() {
+    ///     reversed.push(combo.cloned().collect::<Vec<u32>>());
+    /// }
+    ///
+    /// assert_eq!(reversed, vec![vec![1, 2, 3], vec![0, 2, 3], vec![0, 1, 3], vec![0, 1, 2]]);
+    /// ```
+    pub fn prev(&'b mut self) -> Option<CombinationIter<'a, 'b, T>> {
+        // When bounded, stop at the start of the range rather than stepping into another's
+        let bounded = self.left.is_some();
+        if !self.inited {
+            if self.left == Some(0) || bounded && self.position() < self.start {
+                return None;
+            }
+
+            self.inited = true;
+            self.changed = 0;
+            if let Some(ref mut left) = self.left {
+                *left -= 1;
+            }
+        } else {
+            if bounded && self.position() <= self.start + 1 {
+                return None;
+            }
 
-            return Some(CombinationIter {
-                it: indices.iter(),
-                seq: self.seq,
-            });
+            self.changed = retreat(&mut self.indices, self.seq.len())?;
+            if let Some(ref mut left) = self.left {
+                *left += 1;
+            }
         }
 
-        for i in (0..k).rev() {
-            // Try and increment this index
-            indices[i] += 1;
+        Some(CombinationIter {
+            it: self.indices.iter(),
+            seq: self.seq,
+        })
+    }
+}
 
-            if indices[i] == seq_len - k + 1 + i {
-                // Index has overflowed, try parent index
-                continue;
+impl<'a, T> Combinator<'a, T> {
+    /// Repositions the combinator at the last combination it would produce, so that the next call
+    /// to `next` or `prev` produces it.
+    pub fn seek_last(&mut self) {
+        if self.left.is_some() {
+            // An empty range has no last combination, and `end - 1` belongs to another range
+            let end = self.end();
+            if end > self.start {
+                self.seek(end - 1);
             }
+            return;
+        }
+
+        let (n, k) = (self.seq.len(), self.indices.len());
+        for (j, index) in self.indices.iter_mut().enumerate() {
+            *index = n - k + j;
+        }
+        self.inited = false;
+    }
+
+    /// Returns the number of combinations still to be produced by `next`, or `None` if it does not
+    /// fit in a `u128`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use combo::combinations;
+    ///
+    /// let sequence: Vec<u32> = (0..5).collect();
+    /// let mut combinator = combinations(&sequence[..], 3);
+    /// assert_eq!(combinator.remaining(), Some(10));
+    ///
+    /// combinator.next();
+    /// combinator.next();
+    /// assert_eq!(combinator.remaining(), Some(8));
+    /// ```
+    pub fn remaining(&self) -> Option<u128> {
+        match (count::remaining(&self.indices, self.seq.len(), self.inited), self.left) {
+            (Some(remaining), Some(left)) => Some(remaining.min(left)),
+            (remaining, None) => remaining,
+            (None, left) => left,
+        }
+    }
+
+    /// Returns the number of combinations still to be produced by `next` as an arbitrary
+    /// precision integer.
+    #[cfg(feature = "num-bigint")]
+    pub fn remaining_big(&self) -> num_bigint::BigUint {
+        let remaining = count::remaining_big(&self.indices, self.seq.len(), self.inited);
+        match self.left {
+            Some(left) => remaining.min(left.into()),
+            None => remaining,
+        }
+    }
+
+    /// Returns the indices into the sequence of the current combination, or of the combination the
+    /// next call to `next` will produce if it has not been called since construction or the last
+    /// `seek`.
+    pub fn indices(&self) -> &[usize] {
+        &self.indices
+    }
+
+    /// Returns the first position within the current combination which differs from the previous
+    /// one, so that only elements from that position onwards have changed. This is `0` for the
+    /// first combination produced.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use combo::combinations;
+    ///
+    /// let sequence = [1, 2, 3, 4, 5];
+    /// let mut combinator = combinations(&sequence[..], 3);
+    ///
+    /// // Running sums of each prefix of the current combination
+    /// let mut prefix_sums = [0; 4];
+    /// while let Some(combo) = combinator.next() {
+    ///     let elements: Vec<&i32> = combo.collect();
+    ///     for i in combinator.changed_from()..3 {
+    ///         prefix_sums[i + 1] = prefix_sums[i] + elements[i];
+    ///     }
+    ///     assert_eq!(prefix_sums[3], elements.into_iter().sum());
+    /// }
+    /// ```
+    pub fn changed_from(&self) -> usize {
+        self.changed
+    }
+
+    /// Returns the position in lexicographic order of the combination most recently produced by
+    /// `next`, or `None` if `next` has not been called since construction or the last `seek`.
+    ///
+    /// # Panics
+    /// When the number of combinations does not fit in a `u128`.
+    pub fn rank(&self) -> Option<u128> {
+        if self.inited {
+            Some(rank_combination(&self.indices, self.seq.len()))
+        } else {
+            None
+        }
+    }
+
+    /// Repositions the combinator so that the next call to `next` produces the combination at
+    /// position `rank` in lexicographic order.
+    ///
+    /// Together with `rank`, this allows enumeration to be resumed or sharded. If the combinator
+    /// was produced by `split_at` or `chunks`, it still stops at the end of its range.
+    ///
+    /// # Panics
+    /// When `rank` is not less than the total number of combinations.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use combo::combinations;
+    ///
+    /// let sequence: Vec<u32> = (0..5).collect();
+    /// let mut combinator = combinations(&sequence[..], 3);
+    ///
+    /// combinator.seek(4);
+    /// let combination: Vec<&u32> = combinator.next().unwrap().collect();
+    /// assert_eq!(combination, vec![&0, &2, &4]);
+    /// assert_eq!(combinator.rank(), Some(4));
+    /// ```
+    pub fn seek(&mut self, rank: u128) {
+        let end = self.left.map(|_| self.end());
+        rank::unrank_into(rank, self.seq.len(), &mut self.indices);
+        self.inited = false;
+        self.left = end.map(|end| end.saturating_sub(rank));
+    }
 
-            // Reset child indices
-            for j in i + 1..k {
-                indices[j] = indices[j - 1] + 1;
+    /// Rewinds the combinator so that the next call to `next` produces the first combination
+    /// again, reusing its allocation. A combinator from `split_at` or `chunks` rewinds to the first
+    /// combination of its own range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use combo::combinations;
+    ///
+    /// let sequence: Vec<u32> = (0..5).collect();
+    /// let mut combinator = combinations(&sequence[..], 3);
+    ///
+    /// while combinator.next().is_some() {}
+    /// combinator.reset();
+    /// assert_eq!(combinator.remaining(), Some(10));
+    /// ```
+    pub fn reset(&mut self) {
+        match self.left {
+            Some(_) => {
+                let end = self.end();
+                if self.start < end {
+                    rank::unrank_into(self.start, self.seq.len(), &mut self.indices);
+                }
+                self.left = Some(end - self.start);
             }
+            None => {
+                for (i, index) in self.indices.iter_mut().enumerate() {
+                    *index = i;
+                }
+            }
+        }
+        self.inited = false;
+        self.changed = 0;
+    }
+
+    /// Returns a combinator over `seq` with the same combination length and range, starting from
+    /// its first combination and reusing this combinator's allocation.
+    ///
+    /// # Panics
+    /// When `seq` is not the same length as the original sequence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use combo::combinations;
+    ///
+    /// let first = ['a', 'b', 'c'];
+    /// let second = ['x', 'y', 'z'];
+    ///
+    /// let mut combinator = combinations(&first[..], 2);
+    /// combinator.next();
+    ///
+    /// let mut combinator = combinator.rebind(&second[..]);
+    /// assert_eq!(combinator.next().unwrap().collect::<String>(), "xy");
+    /// ```
+    pub fn rebind<'c, U>(mut self, seq: &'c [U]) -> Combinator<'c, U> {
+        if seq.len() != self.seq.len() {
+            panic!("Sequence length differs ({} != {})", seq.len(), self.seq.len());
+        }
 
-            return Some(CombinationIter {
-                it: indices.iter(),
-                seq: self.seq,
-            });
+        self.reset();
+        Combinator {
+            seq,
+            indices: self.indices,
+            inited: false,
+            changed: 0,
+            left: self.left,
+            start: self.start,
         }
+    }
+}
+
+/// Advances `indices` to the lexicographically next combination of `seq_len` elements, returning
+/// the first position changed, or `None` (leaving `indices` untouched) if it already holds the
+/// last one.
+pub(crate) fn advance(indices: &mut [usize], seq_len: usize) -> Option<usize> {
+    let k = indices.len();
+
+    for i in (0..k).rev() {
+        // Try and increment this index
+        if indices[i] + 1 == seq_len - k + 1 + i {
+            // Index would overflow, try parent index
+            continue;
+        }
+
+        indices[i] += 1;
+
+        // Reset child indices
+        for j in i + 1..k {
+            indices[j] = indices[j - 1] + 1;
+        }
+
+        return Some(i);
+    }
+
+    None
+}
+
+/// Steps `indices` back to the lexicographically previous combination of `seq_len` elements,
+/// returning the first position changed, or `None` (leaving `indices` untouched) if it already
+/// holds the first one.
+pub(crate) fn retreat(indices: &mut [usize], seq_len: usize) -> Option<usize> {
+    let k = indices.len();
+
+    // Find the last index with room below it
+    let i = (0..k).rev().find(|&i| indices[i] > if i == 0 { 0 } else { indices[i - 1] + 1 })?;
+    indices[i] -= 1;
 
-        return None;
+    // Push child indices as high as they go
+    for (j, index) in indices.iter_mut().enumerate().skip(i + 1) {
+        *index = seq_len - k + j;
+    }
+
+    Some(i)
+}
+
+impl<'a, 'b, T> LendingItem<'b> for Combinator<'a, T> {
+    type Item = CombinationIter<'a, 'b, T>;
+}
+
+impl<'a, T> LendingIterator for Combinator<'a, T> {
+    fn next(&mut self) -> Option<CombinationIter<'a, '_, T>> {
+        Combinator::next(self)
     }
 }
 
@@ -62,6 +433,15 @@ pub struct CombinationIter<'a, 'b, T>
     seq: &'a [T],
 }
 
+impl<'a, 'b, T> Clone for CombinationIter<'a, 'b, T> {
+    fn clone(&self) -> Self {
+        CombinationIter {
+            it: self.it.clone(),
+            seq: self.seq,
+        }
+    }
+}
+
 impl<'a, 'b, T> Iterator for CombinationIter<'a, 'b, T> {
     type Item = &'a T;
 
@@ -74,6 +454,51 @@ impl<'a, 'b, T> Iterator for CombinationIter<'a, 'b, T> {
     }
 }
 
+impl<'a, 'b, T> CombinationIter<'a, 'b, T> {
+    /// Returns the indices into the sequence of the elements yet to be yielded.
+    pub fn indices(&self) -> &'b [usize] {
+        self.it.as_slice()
+    }
+
+    /// Converts into an `Iterator` yielding each element together with its index into the
+    /// sequence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use combo::combinations;
+    ///
+    /// let sequence = ['a', 'b', 'c', 'd'];
+    /// let mut combinator = combinations(&sequence[..], 2);
+    /// combinator.seek(4);
+    ///
+    /// let pairs: Vec<(usize, &char)> = combinator.next().unwrap().enumerate_indices().collect();
+    /// assert_eq!(pairs, vec![(1, &'b'), (3, &'d')]);
+    /// ```
+    pub fn enumerate_indices(self) -> EnumerateIndices<'a, 'b, T> {
+        EnumerateIndices { inner: self }
+    }
+}
+
+/// An `Iterator` yielding the elements of a particular combination with their indices into the
+/// sequence.
+///
+/// Created by `CombinationIter::enumerate_indices`.
+pub struct EnumerateIndices<'a, 'b, T>
+    where T: 'a
+{
+    inner: CombinationIter<'a, 'b, T>,
+}
+
+impl<'a, 'b, T> Iterator for EnumerateIndices<'a, 'b, T> {
+    type Item = (usize, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let seq = self.inner.seq;
+        self.inner.it.next().map(|&i| (i, &seq[i]))
+    }
+}
+
 /// Returns a (non-standard) iterator yielding all combinations of length `len` from
 /// the sequence `seq`.
 ///
@@ -93,7 +518,7 @@ impl<'a, 'b, T> Iterator for CombinationIter<'a, 'b, T> {
 ///
 /// let mut i = 0;
 /// while let Some(combo) = combinator.next() {
-/// 	let combination: Vec<&u32> = combo.collect();
+///     let combination: Vec<&u32> = combo.collect();
 ///     println!("{}: {:?}", i, combination);
 ///     i += 1;
 /// }
@@ -112,17 +537,40 @@ impl<'a, 'b, T> Iterator for CombinationIter<'a, 'b, T> {
 /// ```
 ///
 pub fn combinations<'a, T>(seq: &'a [T], len: usize) -> Combinator<'a, T> {
-    if len > seq.len() {
-        panic!("Combination length longer than sequence ({} > {})",
-               len,
-               seq.len());
+    match try_combinations(seq, len) {
+        Ok(combinator) => combinator,
+        Err(err) => panic!("{}", err),
     }
+}
+
+/// Returns a (non-standard) iterator yielding all combinations of length `len` from
+/// the sequence `seq`, or an error if `len` is longer than the sequence.
+///
+/// This is the non-panicking counterpart to `combinations`, for when `len` comes from untrusted
+/// input.
+///
+/// # Examples
+///
+/// ```
+/// use combo::{try_combinations, ComboError};
+///
+/// let sequence: Vec<u32> = (0..5).collect();
+///
+/// assert!(try_combinations(&sequence[..], 3).is_ok());
+/// assert_eq!(try_combinations(&sequence[..], 6).err(),
+///            Some(ComboError::LengthTooLong { len: 6, seq_len: 5 }));
+/// ```
+pub fn try_combinations<'a, T>(seq: &'a [T], len: usize) -> Result<Combinator<'a, T>, ComboError> {
+    error::check_len(len, seq.len())?;
 
-    Combinator {
-        seq: seq,
+    Ok(Combinator {
+        seq,
         indices: (0..len).collect(),
         inited: false,
-    }
+        changed: 0,
+        left: None,
+        start: 0,
+    })
 }
 
 #[test]
@@ -149,9 +597,156 @@ fn all_combinations_generated() {
     }
 }
 
+#[test]
+fn remaining_counts_down_to_zero() {
+    let sequence: Vec<u32> = (0..7).collect();
+
+    let mut combinator = combinations(&sequence[..], 4);
+    let mut expected = binomial(7, 4).unwrap();
+    while combinator.next().is_some() {
+        expected -= 1;
+        assert_eq!(combinator.remaining(), Some(expected));
+    }
+    assert_eq!(expected, 0);
+}
+
+#[test]
+fn changed_from_marks_unchanged_prefix() {
+    let sequence: Vec<u32> = (0..6).collect();
+
+    let mut combinator = combinations(&sequence[..], 4);
+    let mut previous: Vec<u32> = Vec::new();
+    while let Some(combo) = combinator.next() {
+        let current: Vec<u32> = combo.cloned().collect();
+        let changed = combinator.changed_from();
+
+        if !previous.is_empty() {
+            assert_eq!(previous[..changed], current[..changed]);
+            assert!(previous[changed] != current[changed]);
+        } else {
+            assert_eq!(changed, 0);
+        }
+        previous = current;
+    }
+}
+
+#[test]
+fn indices_match_elements() {
+    let sequence: Vec<u32> = (10..16).collect();
+
+    let mut combinator = combinations(&sequence[..], 3);
+    while let Some(combo) = combinator.next() {
+        let indices = combo.indices().to_vec();
+        for (i, element) in combo.enumerate_indices() {
+            assert_eq!(*element, sequence[i]);
+        }
+        assert_eq!(&indices[..], combinator.indices());
+    }
+}
+
+#[test]
+fn prev_reverses_next() {
+    let sequence: Vec<u32> = (0..7).collect();
+
+    let forward: Vec<Vec<&u32>> = combinations(&sequence[..], 3).owned().collect();
+
+    let mut combinator = combinations(&sequence[..], 3);
+    combinator.seek_last();
+    let mut backward = Vec::new();
+    while let Some(combo) = combinator.prev() {
+        backward.push(combo.collect::<Vec<_>>());
+    }
+    backward.reverse();
+    assert_eq!(forward, backward);
+
+    // Stepping back and forth revisits the same combinations
+    let mut combinator = combinations(&sequence[..], 3);
+    combinator.seek(10);
+    combinator.next();
+    let previous: Vec<&u32> = combinator.prev().unwrap().collect();
+    assert_eq!(previous, forward[9]);
+    let next: Vec<&u32> = combinator.next().unwrap().collect();
+    assert_eq!(next, forward[10]);
+    assert_eq!(combinator.remaining(), Some(24));
+}
+
+#[test]
+fn seek_resumes_enumeration() {
+    let sequence: Vec<u32> = (0..6).collect();
+
+    let all: Vec<Vec<&u32>> = combinations(&sequence[..], 3).owned().collect();
+
+    let mut combinator = combinations(&sequence[..], 3);
+    assert_eq!(combinator.rank(), None);
+    combinator.seek(7);
+    let resumed: Vec<Vec<&u32>> = combinator.owned().collect();
+    assert_eq!(&all[7..], &resumed[..]);
+}
+
 #[test]
 #[should_panic]
 fn panics_on_invalid_combination_length() {
     let sequence: Vec<u32> = (0..4).collect();
     combinations(&sequence[..], sequence.len() + 1);
 }
+
+#[test]
+fn errors_on_invalid_combination_length() {
+    let sequence: Vec<u32> = (0..4).collect();
+
+    let err = try_combinations(&sequence[..], 5).err().unwrap();
+    assert_eq!(err, ComboError::LengthTooLong { len: 5, seq_len: 4 });
+    assert_eq!(err.to_string(), "Combination length longer than sequence (5 > 4)");
+}
+
+#[test]
+fn reset_and_rebind_reuse_allocation() {
+    let first: Vec<u32> = (0..5).collect();
+    let second: Vec<u32> = (10..15).collect();
+
+    let all: Vec<Vec<u32>> = combinations(&first[..], 3).cloned().collect();
+
+    let mut combinator = combinations(&first[..], 3);
+    while combinator.next().is_some() {}
+    let buffer = combinator.indices().as_ptr();
+    combinator.reset();
+    assert_eq!(combinator.indices().as_ptr(), buffer);
+    assert_eq!(combinator.cloned().collect::<Vec<_>>(), all);
+
+    // Chunks rewind to the start of their own range
+    let mut chunk = combinations(&first[..], 3).chunks(3).swap_remove(1);
+    while chunk.next().is_some() {}
+    chunk.reset();
+    assert_eq!(chunk.remaining(), Some(3));
+    assert_eq!(chunk.cloned().collect::<Vec<_>>(), &all[4..7]);
+
+    // A head split from an advanced combinator starts where the split was made
+    let mut combinator = combinations(&first[..], 3);
+    combinator.next();
+    combinator.next();
+    let (mut head, _) = combinator.split_at(4);
+    head.next();
+    head.reset();
+    assert_eq!(head.cloned().collect::<Vec<_>>(), &all[2..4]);
+
+    // An empty tail stays empty
+    let (_, mut tail) = combinations(&first[..], 3).split_at(10);
+    tail.reset();
+    assert_eq!(tail.remaining(), Some(0));
+    assert!(tail.next().is_none());
+
+    let mut chunk = combinations(&first[..], 3).chunks(3).swap_remove(2);
+    chunk.next();
+    let chunk = chunk.rebind(&second[..]);
+    let combos: Vec<Vec<u32>> = chunk.cloned().collect();
+    assert_eq!(combos, vec![vec![11, 12, 14], vec![11, 13, 14], vec![12, 13, 14]]);
+
+    let mut combinator = combinations(&first[..], 3);
+    combinator.next();
+    let buffer = combinator.indices().as_ptr();
+    let combinator = combinator.rebind(&second[..]);
+    assert_eq!(combinator.indices().as_ptr(), buffer);
+    let combos: Vec<Vec<u32>> = combinator.cloned().collect();
+    assert_eq!(combos[0], vec![10, 11, 12]);
+    assert_eq!(combos.len(), 10);
+}
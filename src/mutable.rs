@@ -0,0 +1,145 @@
+//! Combinations yielding mutable references to their elements.
+
+use std::mem;
+
+use error;
+use {advance, LendingItem, LendingIterator};
+
+/// A non-standard iterator yielding combinations of mutable elements from a sequence.
+///
+/// Created by `combinations_mut`.
+pub struct CombinatorMut<'a, T>
+    where T: 'a
+{
+    seq: &'a mut [T],
+    indices: Vec<usize>,
+    inited: bool,
+}
+
+impl<'a, T> CombinatorMut<'a, T> {
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> Option<CombinationIterMut<'_, T>> {
+        // First combination is special cased
+        if !self.inited {
+            self.inited = true;
+        } else {
+            advance(&mut self.indices, self.seq.len())?;
+        }
+
+        Some(CombinationIterMut {
+            it: self.indices.iter(),
+            rest: &mut self.seq[..],
+            offset: 0,
+        })
+    }
+}
+
+impl<'a, 'b, T> LendingItem<'b> for CombinatorMut<'a, T> {
+    type Item = CombinationIterMut<'b, T>;
+}
+
+impl<'a, T> LendingIterator for CombinatorMut<'a, T> {
+    fn next(&mut self) -> Option<CombinationIterMut<'_, T>> {
+        CombinatorMut::next(self)
+    }
+}
+
+/// An `Iterator` yielding mutable references to elements of a particular combination.
+pub struct CombinationIterMut<'b, T>
+    where T: 'b
+{
+    it: ::std::slice::Iter<'b, usize>,
+    // The part of the sequence after the last element yielded, which starts at index `offset`
+    rest: &'b mut [T],
+    offset: usize,
+}
+
+impl<'b, T> Iterator for CombinationIterMut<'b, T> {
+    type Item = &'b mut T;
+
+    fn next(&mut self) -> Option<&'b mut T> {
+        let &i = self.it.next()?;
+
+        // Indices are strictly increasing, so each element lies beyond those already yielded and
+        // the references can be split off the sequence without overlapping
+        let rest = mem::take(&mut self.rest);
+        let (element, rest) = rest[i - self.offset..].split_first_mut().unwrap();
+        self.rest = rest;
+        self.offset = i + 1;
+
+        Some(element)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.it.size_hint()
+    }
+}
+
+impl<'b, T> ExactSizeIterator for CombinationIterMut<'b, T> {}
+
+/// Returns a (non-standard) iterator yielding all combinations of length `len` from the sequence
+/// `seq`, in the same order as `combinations`.
+///
+/// Each combination is an `Iterator` yielding a mutable reference to each of its elements, so
+/// they can be updated in place.
+///
+/// # Panics
+/// When attempting to iterate over combination lengths longer than the original sequence.
+///
+/// # Examples
+///
+/// ```
+/// use combo::combinations_mut;
+///
+/// // Each element interacts with every other once
+/// let mut counts = [0; 4];
+/// let mut combinator = combinations_mut(&mut counts[..], 2);
+/// while let Some(pair) = combinator.next() {
+///     for count in pair {
+///         *count += 1;
+///     }
+/// }
+///
+/// assert_eq!(counts, [3, 3, 3, 3]);
+/// ```
+pub fn combinations_mut<T>(seq: &mut [T], len: usize) -> CombinatorMut<'_, T> {
+    if let Err(err) = error::check_len(len, seq.len()) {
+        panic!("{}", err);
+    }
+
+    CombinatorMut {
+        seq,
+        indices: (0..len).collect(),
+        inited: false,
+    }
+}
+
+#[test]
+fn yields_elements_in_combinations_order() {
+    let mut sequence: Vec<u32> = (0..6).collect();
+    let expected: Vec<Vec<u32>> = ::combinations(&sequence[..], 3).cloned().collect();
+
+    let mut generated = Vec::new();
+    let mut combinator = combinations_mut(&mut sequence[..], 3);
+    while let Some(combo) = combinator.next() {
+        let elements: Vec<&mut u32> = combo.collect();
+        generated.push(elements.into_iter().map(|element| *element).collect::<Vec<_>>());
+    }
+    assert_eq!(generated, expected);
+}
+
+#[test]
+fn updates_elements_in_place() {
+    let mut counts = [0; 4];
+
+    // Count how often each element appears first and second in a pair
+    let mut combinator = combinations_mut(&mut counts[..], 2);
+    while let Some(mut pair) = combinator.next() {
+        let first = pair.next().unwrap();
+        let second = pair.next().unwrap();
+        *first += 1;
+        *second += 10;
+    }
+
+    assert_eq!(counts, [3, 12, 21, 30]);
+}
@@ -0,0 +1,174 @@
+//! Standard iterators yielding each combination as an owned `Vec`.
+
+use count;
+use {retreat, unrank_combination, Combinator};
+
+/// An `Iterator` yielding each combination as a `Vec` of references into the sequence.
+///
+/// Combinations can also be taken from the back, in reverse lexicographic order.
+///
+/// Created by `Combinator::owned`.
+pub struct Owned<'a, T>
+    where T: 'a
+{
+    combinator: Combinator<'a, T>,
+    // The next combination to be produced from the back, once iteration from the back has begun
+    back: Option<Vec<usize>>,
+}
+
+impl<'a, T> Iterator for Owned<'a, T> {
+    type Item = Vec<&'a T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.combinator.next().map(|combo| combo.collect())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        count::size_hint(self.combinator.remaining())
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Owned<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let combinator = &mut self.combinator;
+        let seq = combinator.seq;
+        let (n, k) = (seq.len(), combinator.indices.len());
+
+        // Bound the front by the number of combinations left, so the two ends stop where they meet
+        let back = match self.back {
+            Some(ref mut back) => back,
+            None => {
+                let end = combinator.end();
+                combinator.left = Some(end - combinator.position());
+                if end == 0 {
+                    return None;
+                }
+                self.back.get_or_insert(unrank_combination(end - 1, n, k))
+            }
+        };
+
+        match combinator.left {
+            Some(0) => return None,
+            Some(ref mut left) => *left -= 1,
+            None => unreachable!(),
+        }
+
+        let combination = back.iter().map(|&i| &seq[i]).collect();
+        retreat(back, n);
+        Some(combination)
+    }
+}
+
+/// An `Iterator` yielding each combination as a `Vec` of cloned elements.
+///
+/// Combinations can also be taken from the back, in reverse lexicographic order.
+///
+/// Created by `Combinator::cloned`.
+pub struct Cloned<'a, T>
+    where T: 'a
+{
+    inner: Owned<'a, T>,
+}
+
+impl<'a, T> Iterator for Cloned<'a, T>
+    where T: Clone
+{
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|combo| combo.into_iter().cloned().collect())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Cloned<'a, T>
+    where T: Clone
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back().map(|combo| combo.into_iter().cloned().collect())
+    }
+}
+
+impl<'a, T> Combinator<'a, T> {
+    /// Converts into a standard `Iterator` yielding each remaining combination as a `Vec` of
+    /// references.
+    ///
+    /// # Panics
+    /// When iterating from the back, if the number of combinations does not fit in a `u128`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use combo::combinations;
+    ///
+    /// let sequence = ['a', 'b', 'c'];
+    /// let pairs: Vec<Vec<&char>> = combinations(&sequence[..], 2).owned().collect();
+    ///
+    /// assert_eq!(pairs, vec![vec![&'a', &'b'], vec![&'a', &'c'], vec![&'b', &'c']]);
+    /// ```
+    pub fn owned(self) -> Owned<'a, T> {
+        Owned {
+            combinator: self,
+            back: None,
+        }
+    }
+
+    /// Converts into a standard `Iterator` yielding each remaining combination as a `Vec` of
+    /// cloned elements.
+    pub fn cloned(self) -> Cloned<'a, T>
+        where T: Clone
+    {
+        Cloned { inner: self.owned() }
+    }
+}
+
+#[cfg(test)]
+use combinations;
+
+#[test]
+fn owned_and_cloned_agree() {
+    let sequence: Vec<u32> = (0..5).collect();
+
+    let owned: Vec<Vec<u32>> = combinations(&sequence[..], 3)
+                                   .owned()
+                                   .map(|combo| combo.into_iter().cloned().collect())
+                                   .collect();
+    let cloned: Vec<Vec<u32>> = combinations(&sequence[..], 3).cloned().collect();
+
+    assert_eq!(combinations(&sequence[..], 3).owned().size_hint(), (10, Some(10)));
+    assert_eq!(owned.len(), 10);
+    assert_eq!(owned, cloned);
+    assert_eq!(cloned[3], vec![0, 2, 3]);
+
+    // More combinations than fit in a `usize` have only a lower bound
+    let sequence: Vec<u32> = (0..70).collect();
+    assert_eq!(combinations(&sequence[..], 35).owned().size_hint(), (usize::MAX, None));
+}
+
+#[test]
+fn double_ended_iteration_meets_in_the_middle() {
+    let sequence: Vec<u32> = (0..7).collect();
+
+    let forward: Vec<Vec<u32>> = combinations(&sequence[..], 3).cloned().collect();
+
+    let mut backward: Vec<Vec<u32>> = combinations(&sequence[..], 3).cloned().rev().collect();
+    backward.reverse();
+    assert_eq!(backward, forward);
+
+    for split in 0..forward.len() + 1 {
+        let mut combinator = combinations(&sequence[..], 3);
+        combinator.seek(3);
+
+        let mut iter = combinator.cloned();
+        let mut tail: Vec<Vec<u32>> = iter.by_ref().rev().take(split).collect();
+        tail.reverse();
+        assert_eq!(iter.size_hint().0, (forward.len() - 3).saturating_sub(split));
+
+        let mut both: Vec<Vec<u32>> = iter.collect();
+        both.extend(tail);
+        assert_eq!(&both[..], &forward[3..]);
+    }
+}
@@ -0,0 +1,230 @@
+//! Parallel iteration over combinations with rayon, enabled by the `rayon` feature.
+
+use rayon::iter::plumbing::{bridge, bridge_unindexed, Consumer, Folder, Producer, ProducerCallback,
+                            UnindexedConsumer, UnindexedProducer};
+use rayon::iter::{IndexedParallelIterator, IntoParallelIterator, ParallelIterator};
+
+use count;
+use {combinations, Combinator, Owned};
+
+/// A `ParallelIterator` yielding each combination as a `Vec` of references into the sequence.
+///
+/// Created by `par_combinations`, or by `into_par_iter` on a `Combinator`, in which case only the
+/// combinations it has yet to produce are visited.
+///
+/// Methods of `IndexedParallelIterator` panic when the number of combinations does not fit in a
+/// `usize`, but those of `ParallelIterator` split the combinations by rank regardless.
+pub struct ParCombinations<'a, T>
+    where T: 'a
+{
+    combinator: Combinator<'a, T>,
+}
+
+impl<'a, T> ParallelIterator for ParCombinations<'a, T>
+    where T: Sync
+{
+    type Item = Vec<&'a T>;
+
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+        where C: UnindexedConsumer<Self::Item>
+    {
+        if self.opt_len().is_some() {
+            return bridge(self, consumer);
+        }
+
+        let mut combinator = self.combinator;
+        let left = combinator.remaining().expect("Number of combinations overflows u128");
+        combinator.left = Some(left);
+
+        bridge_unindexed(CombinationProducer { combinator }, consumer)
+    }
+
+    fn opt_len(&self) -> Option<usize> {
+        match count::size_hint(self.combinator.remaining()) {
+            (len, Some(_)) => Some(len),
+            _ => None,
+        }
+    }
+}
+
+impl<'a, T> IndexedParallelIterator for ParCombinations<'a, T>
+    where T: Sync
+{
+    fn len(&self) -> usize {
+        self.opt_len().expect("Number of combinations overflows usize")
+    }
+
+    fn drive<C>(self, consumer: C) -> C::Result
+        where C: Consumer<Self::Item>
+    {
+        bridge(self, consumer)
+    }
+
+    fn with_producer<CB>(self, callback: CB) -> CB::Output
+        where CB: ProducerCallback<Self::Item>
+    {
+        let len = self.len() as u128;
+        let mut combinator = self.combinator;
+        combinator.left = Some(len);
+
+        callback.callback(CombinationProducer { combinator })
+    }
+}
+
+impl<'a, T> IntoParallelIterator for Combinator<'a, T>
+    where T: Sync
+{
+    type Iter = ParCombinations<'a, T>;
+    type Item = Vec<&'a T>;
+
+    fn into_par_iter(self) -> Self::Iter {
+        ParCombinations { combinator: self }
+    }
+}
+
+/// Splits a bounded combinator by rank for rayon's work stealing.
+struct CombinationProducer<'a, T>
+    where T: 'a
+{
+    combinator: Combinator<'a, T>,
+}
+
+impl<'a, T> Producer for CombinationProducer<'a, T>
+    where T: Sync
+{
+    type Item = Vec<&'a T>;
+    type IntoIter = ProducerIter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        ProducerIter(self.combinator.owned())
+    }
+
+    fn split_at(self, index: usize) -> (Self, Self) {
+        let rank = self.combinator.position() + index as u128;
+        let (head, tail) = self.combinator.split_at(rank);
+
+        (CombinationProducer { combinator: head }, CombinationProducer { combinator: tail })
+    }
+}
+
+impl<'a, T> UnindexedProducer for CombinationProducer<'a, T>
+    where T: Sync
+{
+    type Item = Vec<&'a T>;
+
+    fn split(self) -> (Self, Option<Self>) {
+        let left = self.combinator.remaining().unwrap();
+        if left < 2 {
+            return (self, None);
+        }
+
+        let rank = self.combinator.position() + left / 2;
+        let (head, tail) = self.combinator.split_at(rank);
+
+        (CombinationProducer { combinator: head }, Some(CombinationProducer { combinator: tail }))
+    }
+
+    fn fold_with<F>(self, folder: F) -> F
+        where F: Folder<Self::Item>
+    {
+        folder.consume_iter(self.combinator.owned())
+    }
+}
+
+/// The iterator over the combinations of a `CombinationProducer`.
+///
+/// `Owned` cannot be an `ExactSizeIterator` in general, but a producer only exists for an
+/// `IndexedParallelIterator`, whose length fits in a `usize`.
+struct ProducerIter<'a, T>(Owned<'a, T>)
+    where T: 'a;
+
+impl<'a, T> Iterator for ProducerIter<'a, T> {
+    type Item = Vec<&'a T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for ProducerIter<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back()
+    }
+}
+
+impl<'a, T> ExactSizeIterator for ProducerIter<'a, T> {}
+
+/// Returns a `ParallelIterator` yielding all combinations of length `len` from the sequence `seq`,
+/// each as a `Vec` of references.
+///
+/// The combinations are split between threads by rank, so as an `IndexedParallelIterator`
+/// collecting them preserves the order of `combinations`.
+///
+/// # Panics
+/// When attempting to iterate over combination lengths longer than the original sequence, or
+/// when the number of combinations does not fit in a `u128`.
+///
+/// # Examples
+///
+/// ```
+/// extern crate combo;
+/// extern crate rayon;
+///
+/// use combo::par_combinations;
+/// use rayon::prelude::*;
+///
+/// # fn main() {
+/// let sequence: Vec<u64> = (0..20).collect();
+/// let sum: u64 = par_combinations(&sequence[..], 3)
+///     .map(|combo| combo.into_iter().product::<u64>())
+///     .sum();
+///
+/// assert_eq!(sum, 920550);
+/// # }
+/// ```
+pub fn par_combinations<T>(seq: &[T], len: usize) -> ParCombinations<'_, T>
+    where T: Sync
+{
+    combinations(seq, len).into_par_iter()
+}
+
+#[test]
+fn collects_in_combinator_order() {
+    let sequence: Vec<u32> = (0..12).collect();
+
+    let sequential: Vec<Vec<&u32>> = combinations(&sequence[..], 5).owned().collect();
+    let parallel: Vec<Vec<&u32>> = par_combinations(&sequence[..], 5).collect();
+    assert_eq!(parallel, sequential);
+
+    let mut reversed: Vec<Vec<&u32>> = par_combinations(&sequence[..], 5).rev().collect();
+    reversed.reverse();
+    assert_eq!(reversed, sequential);
+}
+
+#[test]
+fn starts_from_combinator_position() {
+    let sequence: Vec<u32> = (0..8).collect();
+
+    let mut combinator = combinations(&sequence[..], 3);
+    combinator.seek(20);
+
+    let parallel: Vec<Vec<&u32>> = combinator.into_par_iter().collect();
+    let sequential: Vec<Vec<&u32>> = combinations(&sequence[..], 3).owned().skip(20).collect();
+    assert_eq!(parallel, sequential);
+}
+
+#[test]
+fn searches_more_combinations_than_fit_in_usize() {
+    let sequence: Vec<u32> = (0..70).collect();
+    assert_eq!(par_combinations(&sequence[..], 35).opt_len(), None);
+
+    // Exactly half of the combinations start with 0, so the second half is reached by the first
+    // split and begins with a match
+    let pool = rayon::ThreadPoolBuilder::new().num_threads(4).build().unwrap();
+    let found = pool.install(|| par_combinations(&sequence[..], 35).find_any(|c| *c[0] == 1));
+    assert_eq!(found.map(|c| *c[0]), Some(1));
+}
@@ -0,0 +1,165 @@
+//! Permutations and k-permutations (ordered arrangements) of a sequence.
+
+use error;
+use {CombinationIter, LendingItem, LendingIterator};
+
+/// A non-standard iterator yielding ordered arrangements of elements from a sequence.
+///
+/// Created by `permutations` or `k_permutations`.
+pub struct Permutator<'a, T>
+    where T: 'a
+{
+    seq: &'a [T],
+    // A permutation of every index into `seq`, of which the first `len` form the arrangement
+    indices: Vec<usize>,
+    len: usize,
+    inited: bool,
+}
+
+impl<'a, 'b, T> Permutator<'a, T> {
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&'b mut self) -> Option<CombinationIter<'a, 'b, T>> {
+        // First permutation is special cased
+        if !self.inited {
+            self.inited = true;
+        } else {
+            // Putting the unused indices in descending order makes the next permutation of all
+            // indices also the next arrangement of the first `len`
+            self.indices[self.len..].reverse();
+
+            if !next_permutation(&mut self.indices) {
+                self.indices[self.len..].reverse();
+                return None;
+            }
+        }
+
+        Some(CombinationIter {
+            it: self.indices[..self.len].iter(),
+            seq: self.seq,
+        })
+    }
+}
+
+/// Rearranges `indices` into the lexicographically next permutation, returning `false` (leaving
+/// `indices` untouched) if it is already the last.
+fn next_permutation(indices: &mut [usize]) -> bool {
+    // Find the start of the longest non-increasing suffix
+    let i = match indices.windows(2).rposition(|pair| pair[0] < pair[1]) {
+        Some(i) => i,
+        None => return false,
+    };
+
+    // Swap the pivot with the smallest larger element of the suffix, then put the suffix back in
+    // increasing order
+    let j = indices.iter().rposition(|&index| index > indices[i]).unwrap();
+    indices.swap(i, j);
+    indices[i + 1..].reverse();
+
+    true
+}
+
+impl<'a, 'b, T> LendingItem<'b> for Permutator<'a, T> {
+    type Item = CombinationIter<'a, 'b, T>;
+}
+
+impl<'a, T> LendingIterator for Permutator<'a, T> {
+    fn next(&mut self) -> Option<CombinationIter<'a, '_, T>> {
+        Permutator::next(self)
+    }
+}
+
+/// Returns a (non-standard) iterator yielding all permutations of the sequence `seq`, in
+/// lexicographic order of their indices.
+///
+/// Each permutation is a `CombinationIter`, as with `combinations`.
+///
+/// # Examples
+///
+/// ```
+/// use combo::permutations;
+///
+/// let sequence = ['a', 'b', 'c'];
+/// let mut permutator = permutations(&sequence[..]);
+///
+/// let mut arrangements = Vec::new();
+/// while let Some(perm) = permutator.next() {
+///     arrangements.push(perm.collect::<String>());
+/// }
+///
+/// assert_eq!(arrangements, vec!["abc", "acb", "bac", "bca", "cab", "cba"]);
+/// ```
+pub fn permutations<T>(seq: &[T]) -> Permutator<'_, T> {
+    k_permutations(seq, seq.len())
+}
+
+/// Returns a (non-standard) iterator yielding every ordering of every combination of length `len`
+/// from the sequence `seq`, in lexicographic order of their indices.
+///
+/// # Panics
+/// When attempting to iterate over arrangement lengths longer than the original sequence.
+///
+/// # Examples
+///
+/// ```
+/// use combo::k_permutations;
+///
+/// let sequence = ['a', 'b', 'c'];
+/// let mut permutator = k_permutations(&sequence[..], 2);
+///
+/// let mut arrangements = Vec::new();
+/// while let Some(perm) = permutator.next() {
+///     arrangements.push(perm.collect::<String>());
+/// }
+///
+/// assert_eq!(arrangements, vec!["ab", "ac", "ba", "bc", "ca", "cb"]);
+/// ```
+pub fn k_permutations<T>(seq: &[T], len: usize) -> Permutator<'_, T> {
+    if let Err(err) = error::check_len(len, seq.len()) {
+        panic!("{}", err);
+    }
+
+    Permutator {
+        seq,
+        indices: (0..seq.len()).collect(),
+        len,
+        inited: false,
+    }
+}
+
+#[test]
+fn all_k_permutations_generated_in_order() {
+    let sequence: Vec<usize> = (0..5).collect();
+
+    for k in 0..6 {
+        // Every sequence of `k` distinct indices, built up in lexicographic order
+        let mut expected: Vec<Vec<usize>> = vec![vec![]];
+        for _ in 0..k {
+            let mut extended = Vec::new();
+            for prefix in expected {
+                for i in (0..5).filter(|i| !prefix.contains(i)) {
+                    let mut arrangement = prefix.clone();
+                    arrangement.push(i);
+                    extended.push(arrangement);
+                }
+            }
+            expected = extended;
+        }
+
+        let mut generated = Vec::new();
+        let mut permutator = k_permutations(&sequence[..], k);
+        while let Some(perm) = permutator.next() {
+            generated.push(perm.cloned().collect::<Vec<_>>());
+        }
+        assert_eq!(generated, expected);
+
+        // Exhausted permutators stay exhausted
+        assert!(permutator.next().is_none());
+    }
+}
+
+#[test]
+#[should_panic]
+fn panics_on_invalid_arrangement_length() {
+    let sequence: Vec<u32> = (0..4).collect();
+    k_permutations(&sequence[..], 5);
+}
@@ -0,0 +1,227 @@
+//! Enumeration of every subset of a sequence.
+
+use std::ops::RangeInclusive;
+
+use advance;
+use {CombinationIter, LendingItem, LendingIterator};
+
+/// The order in which a `PowerSet` produces subsets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PowerSetOrder {
+    /// Subsets in increasing size, and each size in the lexicographic order of `combinations`.
+    BySize,
+    /// Subsets in the order of a binary counter in which element `i` is bit `i`.
+    Binary,
+}
+
+/// A non-standard iterator yielding subsets of a sequence.
+///
+/// Created by `power_set`.
+pub struct PowerSet<'a, T>
+    where T: 'a
+{
+    seq: &'a [T],
+    order: PowerSetOrder,
+    min_len: usize,
+    max_len: usize,
+    indices: Vec<usize>,
+    inited: bool,
+}
+
+impl<'a, 'b, T> PowerSet<'a, T> {
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&'b mut self) -> Option<CombinationIter<'a, 'b, T>> {
+        let seq_len = self.seq.len();
+        let max_len = self.max_len.min(seq_len);
+        if self.min_len > max_len {
+            return None;
+        }
+
+        match self.order {
+            PowerSetOrder::BySize => {
+                if !self.inited {
+                    self.inited = true;
+                    self.indices = (0..self.min_len).collect();
+                } else if advance(&mut self.indices, seq_len).is_none() {
+                    // Move on to the first combination of the next size
+                    let len = self.indices.len();
+                    if len == max_len {
+                        return None;
+                    }
+
+                    self.indices.clear();
+                    self.indices.extend(0..len + 1);
+                }
+            }
+            PowerSetOrder::Binary => {
+                if !self.inited {
+                    self.inited = true;
+                    self.indices.clear();
+                    self.indices.extend(0..self.min_len);
+                } else if !increment(&mut self.indices, seq_len, self.min_len, max_len) {
+                    return None;
+                }
+            }
+        }
+
+        Some(CombinationIter {
+            it: self.indices.iter(),
+            seq: self.seq,
+        })
+    }
+}
+
+/// Advances the binary counter whose set bits are `indices` to the next value with between
+/// `min_len` and `max_len` bits set, returning `false` (leaving `indices` untouched) if there is
+/// none below `2^seq_len`.
+fn increment(indices: &mut Vec<usize>, seq_len: usize, min_len: usize, max_len: usize) -> bool {
+    // Every larger value keeps the bits above some clear bit `j`, sets `j` and may set any bits
+    // below it. The smallest comes from the lowest `j` for which a count in range is reachable,
+    // filling in the lowest bits as needed.
+    let mut below = 0;
+    for j in 0..seq_len {
+        if below < indices.len() && indices[below] == j {
+            below += 1;
+            continue;
+        }
+
+        let kept = indices.len() - below + 1;
+        if kept <= max_len && kept + j >= min_len {
+            let fill = min_len.saturating_sub(kept);
+            indices.splice(..below, (0..fill).chain(Some(j)));
+            return true;
+        }
+    }
+
+    false
+}
+
+impl<'a, T> PowerSet<'a, T> {
+    /// Sets the order in which subsets are produced, restarting enumeration.
+    pub fn order(mut self, order: PowerSetOrder) -> Self {
+        self.order = order;
+        self.inited = false;
+        self
+    }
+
+    /// Restricts enumeration to subsets whose size lies in `range`, restarting enumeration.
+    pub fn size_range(mut self, range: RangeInclusive<usize>) -> Self {
+        self.min_len = *range.start();
+        self.max_len = *range.end();
+        self.inited = false;
+        self
+    }
+}
+
+impl<'a, 'b, T> LendingItem<'b> for PowerSet<'a, T> {
+    type Item = CombinationIter<'a, 'b, T>;
+}
+
+impl<'a, T> LendingIterator for PowerSet<'a, T> {
+    fn next(&mut self) -> Option<CombinationIter<'a, '_, T>> {
+        PowerSet::next(self)
+    }
+}
+
+/// Returns a (non-standard) iterator yielding every subset of the sequence `seq`.
+///
+/// By default subsets are produced in increasing size, as if by calling `combinations` for each
+/// size in turn. Use `order` to choose a different order and `size_range` to restrict which sizes
+/// are produced.
+///
+/// # Examples
+///
+/// ```
+/// use combo::{power_set, PowerSetOrder};
+///
+/// let sequence = ['a', 'b', 'c'];
+///
+/// let mut subsets = Vec::new();
+/// let mut all = power_set(&sequence[..]);
+/// while let Some(subset) = all.next() {
+///     subsets.push(subset.collect::<String>());
+/// }
+/// assert_eq!(subsets, vec!["", "a", "b", "c", "ab", "ac", "bc", "abc"]);
+///
+/// let mut subsets = Vec::new();
+/// let mut small = power_set(&sequence[..]).order(PowerSetOrder::Binary).size_range(1..=2);
+/// while let Some(subset) = small.next() {
+///     subsets.push(subset.collect::<String>());
+/// }
+/// assert_eq!(subsets, vec!["a", "b", "ab", "c", "ac", "bc"]);
+/// ```
+pub fn power_set<T>(seq: &[T]) -> PowerSet<'_, T> {
+    PowerSet {
+        seq,
+        order: PowerSetOrder::BySize,
+        min_len: 0,
+        max_len: seq.len(),
+        indices: Vec::with_capacity(seq.len()),
+        inited: false,
+    }
+}
+
+#[cfg(test)]
+fn subsets(mut power_set: PowerSet<usize>) -> Vec<Vec<usize>> {
+    let mut subsets = Vec::new();
+    while let Some(subset) = power_set.next() {
+        subsets.push(subset.cloned().collect());
+    }
+    subsets
+}
+
+#[test]
+fn both_orders_cover_every_subset() {
+    let sequence: Vec<usize> = (0..6).collect();
+
+    let binary = subsets(power_set(&sequence[..]).order(PowerSetOrder::Binary));
+    assert_eq!(binary.len(), 64);
+    for (mask, subset) in binary.iter().enumerate() {
+        let expected: Vec<usize> = (0..6).filter(|i| mask & (1 << i) != 0).collect();
+        assert_eq!(subset, &expected);
+    }
+
+    let by_size = subsets(power_set(&sequence[..]));
+    assert_eq!(by_size.len(), 64);
+    for pair in by_size.windows(2) {
+        assert!((pair[0].len(), &pair[0]) < (pair[1].len(), &pair[1]));
+    }
+}
+
+#[test]
+fn size_range_restricts_subsets() {
+    let sequence: Vec<usize> = (0..5).collect();
+
+    for &order in &[PowerSetOrder::BySize, PowerSetOrder::Binary] {
+        let middle = subsets(power_set(&sequence[..]).order(order).size_range(2..=3));
+        assert_eq!(middle.len(), 20);
+        assert!(middle.iter().all(|subset| subset.len() >= 2 && subset.len() <= 3));
+
+        let clamped = subsets(power_set(&sequence[..]).order(order).size_range(4..=9));
+        assert_eq!(clamped.len(), 6);
+
+        assert!(subsets(power_set(&sequence[..]).order(order).size_range(6..=9)).is_empty());
+    }
+}
+
+#[test]
+fn binary_order_skips_to_sizes_in_range() {
+    let sequence: Vec<usize> = (0..12).collect();
+
+    let all = subsets(power_set(&sequence[..]).order(PowerSetOrder::Binary));
+    let expected: Vec<Vec<usize>> = all.into_iter()
+                                       .filter(|subset| subset.len() >= 3 && subset.len() <= 5)
+                                       .collect();
+    let ranged = subsets(power_set(&sequence[..]).order(PowerSetOrder::Binary).size_range(3..=5));
+    assert_eq!(ranged, expected);
+
+    // Stepping through every counter value in between would take 2^64 steps
+    let sequence: Vec<usize> = (0..64).collect();
+    let order = PowerSetOrder::Binary;
+    let largest = subsets(power_set(&sequence[..]).order(order).size_range(62..=64));
+    assert_eq!(largest.len(), 2016 + 64 + 1);
+    assert_eq!(largest.last().unwrap(), &sequence);
+    for pair in largest.windows(2) {
+        assert!(pair[0].iter().rev().lt(pair[1].iter().rev()));
+    }
+}
@@ -0,0 +1,103 @@
+//! Ranking and unranking of combinations in the lexicographic order produced by `Combinator`.
+
+use count;
+
+/// Returns the position of the combination `indices` of `n` elements in the lexicographic order
+/// produced by `combinations`.
+///
+/// # Panics
+/// When `indices` is not strictly increasing, contains an index not less than `n`, or when the
+/// number of combinations C(`n`, `indices.len()`) does not fit in a `u128`.
+///
+/// # Examples
+///
+/// ```
+/// use combo::rank_combination;
+///
+/// assert_eq!(rank_combination(&[0, 1, 2], 5), 0);
+/// assert_eq!(rank_combination(&[0, 2, 4], 5), 4);
+/// assert_eq!(rank_combination(&[2, 3, 4], 5), 9);
+/// ```
+pub fn rank_combination(indices: &[usize], n: usize) -> u128 {
+    for (i, &index) in indices.iter().enumerate() {
+        if index >= n || (i > 0 && index <= indices[i - 1]) {
+            panic!("Invalid combination of {} elements: {:?}", n, indices);
+        }
+    }
+
+    let total = count::binomial(n, indices.len()).expect("Number of combinations overflows u128");
+    let after = count::remaining(indices, n, true).expect("Number of combinations overflows u128");
+
+    total - 1 - after
+}
+
+/// Returns the combination of length `k` from `n` elements at position `rank` in the
+/// lexicographic order produced by `combinations`.
+///
+/// # Panics
+/// When `rank` is not less than the number of combinations C(`n`, `k`).
+///
+/// # Examples
+///
+/// ```
+/// use combo::unrank_combination;
+///
+/// assert_eq!(unrank_combination(4, 5, 3), vec![0, 2, 4]);
+/// ```
+pub fn unrank_combination(rank: u128, n: usize, k: usize) -> Vec<usize> {
+    let mut indices = vec![0; k];
+    unrank_into(rank, n, &mut indices);
+    indices
+}
+
+/// Writes the combination of `n` elements at position `rank` into `indices`.
+pub(crate) fn unrank_into(mut rank: u128, n: usize, indices: &mut [usize]) {
+    let k = indices.len();
+    match count::binomial(n, k) {
+        Some(total) if rank >= total => {
+            panic!("Combination rank out of range ({} >= {})", rank, total);
+        }
+        _ => {}
+    }
+
+    let mut next = 0;
+    for (i, index) in indices.iter_mut().enumerate() {
+        // Skip past every block of combinations sharing the first `i` elements but with a smaller
+        // element at position `i`. A block too large to count is necessarily larger than `rank`.
+        loop {
+            let block = count::binomial(n - 1 - next, k - 1 - i).unwrap_or(u128::MAX);
+            if rank < block {
+                break;
+            }
+            rank -= block;
+            next += 1;
+        }
+
+        *index = next;
+        next += 1;
+    }
+}
+
+#[cfg(test)]
+use combinations;
+
+#[test]
+fn ranks_follow_combinator_order() {
+    let sequence: Vec<usize> = (0..7).collect();
+
+    let mut combinator = combinations(&sequence[..], 3);
+    let mut rank = 0;
+    while let Some(combo) = combinator.next() {
+        let indices: Vec<usize> = combo.cloned().collect();
+        assert_eq!(rank_combination(&indices, 7), rank);
+        assert_eq!(unrank_combination(rank, 7, 3), indices);
+        rank += 1;
+    }
+    assert_eq!(rank, 35);
+}
+
+#[test]
+#[should_panic]
+fn unrank_panics_out_of_range() {
+    unrank_combination(35, 7, 3);
+}
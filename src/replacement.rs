@@ -0,0 +1,143 @@
+//! Combinations with replacement, i.e. multisets of elements from a sequence.
+
+use count;
+use {CombinationIter, LendingItem, LendingIterator};
+
+/// A non-standard iterator yielding combinations with replacement of elements from a sequence.
+///
+/// Created by `combinations_with_replacement`.
+pub struct CombinatorWithReplacement<'a, T>
+    where T: 'a
+{
+    seq: &'a [T],
+    indices: Vec<usize>,
+    inited: bool,
+}
+
+impl<'a, 'b, T> CombinatorWithReplacement<'a, T> {
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&'b mut self) -> Option<CombinationIter<'a, 'b, T>> {
+        let seq_len = self.seq.len();
+        let k = self.indices.len();
+
+        // There is nothing to choose from
+        if seq_len == 0 && k > 0 {
+            return None;
+        }
+
+        // First combination is special cased
+        if !self.inited {
+            self.inited = true;
+        } else {
+            let indices = &mut self.indices;
+
+            // Find the last index which can still be incremented
+            let i = indices.iter().rposition(|&index| index + 1 < seq_len)?;
+            indices[i] += 1;
+
+            // Reset child indices to the smallest non-decreasing continuation
+            for j in i + 1..k {
+                indices[j] = indices[i];
+            }
+        }
+
+        Some(CombinationIter {
+            it: self.indices.iter(),
+            seq: self.seq,
+        })
+    }
+}
+
+impl<'a, T> CombinatorWithReplacement<'a, T> {
+    /// Returns the number of combinations still to be produced by `next`, or `None` if it does not
+    /// fit in a `u128`.
+    pub fn remaining(&self) -> Option<u128> {
+        let n = self.seq.len();
+        if n == 0 {
+            return Some(if self.indices.is_empty() && !self.inited { 1 } else { 0 });
+        }
+
+        // Adding `i` to the `i`th index maps combinations with replacement of `n` elements onto
+        // combinations without replacement of `n + k - 1` elements, preserving their order
+        let shifted: Vec<usize> = self.indices
+                                      .iter()
+                                      .enumerate()
+                                      .map(|(i, &index)| index + i)
+                                      .collect();
+        count::remaining(&shifted, n + self.indices.len() - 1, self.inited)
+    }
+}
+
+impl<'a, 'b, T> LendingItem<'b> for CombinatorWithReplacement<'a, T> {
+    type Item = CombinationIter<'a, 'b, T>;
+}
+
+impl<'a, T> LendingIterator for CombinatorWithReplacement<'a, T> {
+    fn next(&mut self) -> Option<CombinationIter<'a, '_, T>> {
+        CombinatorWithReplacement::next(self)
+    }
+}
+
+/// Returns a (non-standard) iterator yielding all combinations with replacement of length `len`
+/// from the sequence `seq`, that is every multiset of `len` elements.
+///
+/// Combinations are produced in lexicographic order of their indices, each of which is
+/// non-decreasing. There are `multichoose(seq.len(), len)` of them.
+///
+/// # Examples
+///
+/// ```
+/// use combo::combinations_with_replacement;
+///
+/// let sequence = ['a', 'b', 'c'];
+/// let mut combinator = combinations_with_replacement(&sequence[..], 2);
+///
+/// let mut combinations = Vec::new();
+/// while let Some(combo) = combinator.next() {
+///     combinations.push(combo.collect::<String>());
+/// }
+///
+/// assert_eq!(combinations, vec!["aa", "ab", "ac", "bb", "bc", "cc"]);
+/// ```
+pub fn combinations_with_replacement<'a, T>(seq: &'a [T],
+                                            len: usize)
+                                            -> CombinatorWithReplacement<'a, T> {
+    CombinatorWithReplacement {
+        seq,
+        indices: vec![0; len],
+        inited: false,
+    }
+}
+
+#[test]
+fn all_multisets_generated_in_order() {
+    let sequence: Vec<usize> = (0..4).collect();
+
+    let mut expected = Vec::new();
+    for a in 0..4 {
+        for b in a..4 {
+            for c in b..4 {
+                expected.push(vec![a, b, c]);
+            }
+        }
+    }
+
+    let mut combinator = combinations_with_replacement(&sequence[..], 3);
+    assert_eq!(combinator.remaining(), count::multichoose(4, 3));
+
+    let mut generated = Vec::new();
+    while let Some(combo) = combinator.next() {
+        generated.push(combo.cloned().collect::<Vec<_>>());
+        assert_eq!(combinator.remaining(), Some((expected.len() - generated.len()) as u128));
+    }
+    assert_eq!(generated, expected);
+}
+
+#[test]
+fn empty_sequence() {
+    let sequence: Vec<u32> = Vec::new();
+
+    assert_eq!(combinations_with_replacement(&sequence[..], 0).count(), 1);
+    assert_eq!(combinations_with_replacement(&sequence[..], 2).count(), 0);
+    assert_eq!(combinations_with_replacement(&sequence[..], 2).remaining(), Some(0));
+}
@@ -0,0 +1,200 @@
+//! Combinations in revolving door order, where successive combinations differ by a single swap.
+
+use error;
+use {CombinationIter, LendingItem, LendingIterator};
+
+/// A non-standard iterator yielding combinations of elements from a sequence in revolving door
+/// (minimal change) order.
+///
+/// Created by `revolving_door`.
+pub struct RevolvingDoor<'a, T>
+    where T: 'a
+{
+    seq: &'a [T],
+    // Knuth's c_1 < ... < c_k at positions 1 to k, with the sentinel c_{k+1} = n
+    c: Vec<usize>,
+    inited: bool,
+    swap: Option<(usize, usize)>,
+}
+
+impl<'a, 'b, T> RevolvingDoor<'a, T> {
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&'b mut self) -> Option<CombinationIter<'a, 'b, T>> {
+        let k = self.c.len() - 2;
+
+        // First combination is special cased
+        if !self.inited {
+            self.inited = true;
+        } else {
+            if k == 0 {
+                return None;
+            }
+
+            self.swap = Some(step(&mut self.c)?);
+        }
+
+        Some(CombinationIter {
+            it: self.c[1..k + 1].iter(),
+            seq: self.seq,
+        })
+    }
+}
+
+/// Advances `c` by one step of Knuth's Algorithm R (TAOCP 7.2.1.3), returning the (removed,
+/// added) indices, or `None` (leaving `c` untouched) if it already holds the last combination.
+fn step(c: &mut [usize]) -> Option<(usize, usize)> {
+    let t = c.len() - 2;
+
+    // R3: easy case, move c_1 by one
+    if t % 2 == 1 {
+        if c[1] + 1 < c[2] {
+            c[1] += 1;
+            return Some((c[1] - 1, c[1]));
+        }
+    } else if c[1] > 0 {
+        c[1] -= 1;
+        return Some((c[1] + 1, c[1]));
+    }
+
+    let mut j = 2;
+    let mut decrease = t % 2 == 1;
+    while j <= t {
+        let old = (c[j - 1], c[j]);
+
+        if decrease {
+            // R4: try to decrease c_j
+            if c[j] >= j {
+                c[j] = c[j - 1];
+                c[j - 1] = j - 2;
+                return Some(difference(old, (c[j - 1], c[j])));
+            }
+            j += 1;
+        } else {
+            // R5: try to increase c_j
+            if c[j] + 1 < c[j + 1] {
+                c[j - 1] = c[j];
+                c[j] += 1;
+                return Some(difference(old, (c[j - 1], c[j])));
+            }
+            j += 1;
+        }
+
+        decrease = !decrease;
+    }
+
+    None
+}
+
+/// Returns the (removed, added) indices when the pair `old` is replaced by `new`, which have
+/// exactly one index in common.
+fn difference(old: (usize, usize), new: (usize, usize)) -> (usize, usize) {
+    let removed = if old.0 == new.0 || old.0 == new.1 { old.1 } else { old.0 };
+    let added = if new.0 == old.0 || new.0 == old.1 { new.1 } else { new.0 };
+    (removed, added)
+}
+
+impl<'a, T> RevolvingDoor<'a, T> {
+    /// Returns the elements (removed, added) by the step that produced the current combination,
+    /// or `None` for the first combination.
+    pub fn swap(&self) -> Option<(&'a T, &'a T)> {
+        let seq = self.seq;
+        self.swap.map(|(removed, added)| (&seq[removed], &seq[added]))
+    }
+
+    /// Returns the indices into the sequence (removed, added) by the step that produced the
+    /// current combination, or `None` for the first combination.
+    pub fn swap_indices(&self) -> Option<(usize, usize)> {
+        self.swap
+    }
+}
+
+impl<'a, 'b, T> LendingItem<'b> for RevolvingDoor<'a, T> {
+    type Item = CombinationIter<'a, 'b, T>;
+}
+
+impl<'a, T> LendingIterator for RevolvingDoor<'a, T> {
+    fn next(&mut self) -> Option<CombinationIter<'a, '_, T>> {
+        RevolvingDoor::next(self)
+    }
+}
+
+/// Returns a (non-standard) iterator yielding all combinations of length `len` from the sequence
+/// `seq` in revolving door order.
+///
+/// Each combination differs from the previous one by removing exactly one element and adding
+/// exactly one other, reported by `swap`, so that state derived from a combination can be
+/// updated in constant time.
+///
+/// # Panics
+/// When attempting to iterate over combination lengths longer than the original sequence.
+///
+/// # Examples
+///
+/// ```
+/// use combo::revolving_door;
+///
+/// let sequence = [1, 2, 3, 4, 5];
+/// let mut door = revolving_door(&sequence[..], 3);
+///
+/// let mut sum: i32 = door.next().unwrap().sum();
+/// while let Some(combo) = door.next() {
+///     let expected: i32 = combo.sum();
+///
+///     let (removed, added) = door.swap().unwrap();
+///     sum += added - removed;
+///     assert_eq!(sum, expected);
+/// }
+/// ```
+pub fn revolving_door<T>(seq: &[T], len: usize) -> RevolvingDoor<'_, T> {
+    if let Err(err) = error::check_len(len, seq.len()) {
+        panic!("{}", err);
+    }
+
+    let mut c = vec![0];
+    c.extend(0..len);
+    c.push(seq.len());
+
+    RevolvingDoor {
+        seq,
+        c,
+        inited: false,
+        swap: None,
+    }
+}
+
+#[cfg(test)]
+use std::collections::BTreeSet;
+
+#[test]
+fn successive_combinations_differ_by_one_swap() {
+    for n in 0..9 {
+        let sequence: Vec<usize> = (0..n).collect();
+
+        for k in 0..n + 1 {
+            let mut door = revolving_door(&sequence[..], k);
+            let mut seen = BTreeSet::new();
+            let mut previous: Option<BTreeSet<usize>> = None;
+
+            while let Some(combo) = door.next() {
+                let current: BTreeSet<usize> = combo.cloned().collect();
+                assert_eq!(current.len(), k);
+
+                if let Some(previous) = previous {
+                    let (removed, added) = door.swap_indices().unwrap();
+                    let removed_set: Vec<&usize> = previous.difference(&current).collect();
+                    let added_set: Vec<&usize> = current.difference(&previous).collect();
+                    assert_eq!(removed_set, vec![&removed]);
+                    assert_eq!(added_set, vec![&added]);
+                } else {
+                    assert_eq!(door.swap(), None);
+                }
+
+                assert!(seen.insert(current.clone()), "{:?} repeated", current);
+                previous = Some(current);
+            }
+
+            assert_eq!(seen.len() as u128, ::binomial(n, k).unwrap());
+            assert!(door.next().is_none());
+        }
+    }
+}
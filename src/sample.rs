@@ -0,0 +1,187 @@
+//! Uniform random sampling of combinations, enabled by the `rand` feature.
+
+use std::collections::HashSet;
+
+use rand::Rng;
+
+use count;
+use error;
+use rank;
+use CombinationIter;
+
+/// A single combination of elements from a sequence, owning its indices.
+///
+/// Returned by `random_combination` and `sample_combinations`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Combination<'a, T>
+    where T: 'a
+{
+    seq: &'a [T],
+    indices: Vec<usize>,
+}
+
+impl<'a, T> Combination<'a, T> {
+    /// Returns an `Iterator` yielding references to the elements of the combination, as produced
+    /// by `Combinator::next`.
+    pub fn iter(&self) -> CombinationIter<'a, '_, T> {
+        CombinationIter {
+            it: self.indices.iter(),
+            seq: self.seq,
+        }
+    }
+
+    /// Returns the indices into the sequence of the elements of the combination, in increasing
+    /// order.
+    pub fn indices(&self) -> &[usize] {
+        &self.indices
+    }
+}
+
+impl<'a, 'b, T> IntoIterator for &'b Combination<'a, T> {
+    type Item = &'a T;
+    type IntoIter = CombinationIter<'a, 'b, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Returns a combination of length `len` from the sequence `seq`, chosen uniformly at random
+/// using Floyd's algorithm.
+///
+/// # Panics
+/// When the combination length is longer than the original sequence.
+///
+/// # Examples
+///
+/// ```
+/// extern crate combo;
+/// extern crate rand;
+///
+/// use combo::random_combination;
+///
+/// # fn main() {
+/// let sequence: Vec<u32> = (0..100).collect();
+/// let combination = random_combination(&sequence[..], 5, &mut rand::thread_rng());
+///
+/// assert_eq!(combination.iter().count(), 5);
+/// # }
+/// ```
+pub fn random_combination<'a, T, R>(seq: &'a [T], len: usize, rng: &mut R) -> Combination<'a, T>
+    where R: Rng + ?Sized
+{
+    if let Err(err) = error::check_len(len, seq.len()) {
+        panic!("{}", err);
+    }
+
+    // Each of the last `len` indices is added in turn, or if already present replaced by a
+    // random smaller one, which leaves every subset equally likely
+    let mut indices: Vec<usize> = Vec::with_capacity(len);
+    for j in seq.len() - len..seq.len() {
+        let t = rng.gen_range(0..=j);
+        let chosen = match indices.binary_search(&t) {
+            Ok(_) => j,
+            Err(_) => t,
+        };
+
+        let position = indices.binary_search(&chosen).unwrap_err();
+        indices.insert(position, chosen);
+    }
+
+    Combination { seq, indices }
+}
+
+/// Returns `samples` distinct combinations of length `len` from the sequence `seq`, chosen
+/// uniformly at random without replacement and in lexicographic order.
+///
+/// # Panics
+/// When the combination length is longer than the original sequence, the number of combinations
+/// does not fit in a `u128`, or `samples` is larger than the number of combinations.
+///
+/// # Examples
+///
+/// ```
+/// extern crate combo;
+/// extern crate rand;
+///
+/// use combo::sample_combinations;
+///
+/// # fn main() {
+/// let sequence: Vec<u32> = (0..100).collect();
+/// let samples = sample_combinations(&sequence[..], 5, 1000, &mut rand::thread_rng());
+///
+/// assert_eq!(samples.len(), 1000);
+/// # }
+/// ```
+pub fn sample_combinations<'a, T, R>(seq: &'a [T],
+                                     len: usize,
+                                     samples: usize,
+                                     rng: &mut R)
+                                     -> Vec<Combination<'a, T>>
+    where R: Rng + ?Sized
+{
+    if let Err(err) = error::check_len(len, seq.len()) {
+        panic!("{}", err);
+    }
+
+    let total = count::binomial(seq.len(), len).expect("Number of combinations overflows u128");
+    if samples as u128 > total {
+        panic!("Sample larger than number of combinations ({} > {})", samples, total);
+    }
+
+    // Floyd's algorithm again, this time over the ranks of the combinations
+    let mut ranks = HashSet::with_capacity(samples);
+    for j in total - samples as u128..total {
+        let t = rng.gen_range(0..=j);
+        if !ranks.insert(t) {
+            ranks.insert(j);
+        }
+    }
+
+    let mut ranks: Vec<u128> = ranks.into_iter().collect();
+    ranks.sort();
+
+    ranks.into_iter()
+         .map(|r| {
+             Combination {
+                 seq,
+                 indices: rank::unrank_combination(r, seq.len(), len),
+             }
+         })
+         .collect()
+}
+
+#[cfg(test)]
+use rand::rngs::StdRng;
+#[cfg(test)]
+use rand::SeedableRng;
+
+#[test]
+fn random_combinations_are_uniform() {
+    let sequence: Vec<usize> = (0..5).collect();
+    let mut rng = StdRng::seed_from_u64(7);
+
+    let mut counts = [0; 10];
+    for _ in 0..10000 {
+        let combination = random_combination(&sequence[..], 2, &mut rng);
+        assert!(combination.indices().windows(2).all(|pair| pair[0] < pair[1]));
+        counts[rank::rank_combination(combination.indices(), 5) as usize] += 1;
+    }
+
+    assert!(counts.iter().all(|&count| count > 850 && count < 1150), "{:?}", counts);
+}
+
+#[test]
+fn samples_are_distinct_and_ordered() {
+    let sequence: Vec<u32> = (0..8).collect();
+    let mut rng = StdRng::seed_from_u64(7);
+
+    let samples = sample_combinations(&sequence[..], 3, 40, &mut rng);
+    assert_eq!(samples.len(), 40);
+    assert!(samples.windows(2).all(|pair| pair[0].indices() < pair[1].indices()));
+
+    let all = sample_combinations(&sequence[..], 3, 56, &mut rng);
+    let expected: Vec<Vec<&u32>> = ::combinations(&sequence[..], 3).owned().collect();
+    let all: Vec<Vec<&u32>> = all.iter().map(|combination| combination.iter().collect()).collect();
+    assert_eq!(all, expected);
+}
@@ -0,0 +1,199 @@
+//! Enumeration of every combination exactly once in a pseudo-random order.
+
+use count;
+use error;
+use rank;
+use {CombinationIter, LendingItem, LendingIterator};
+
+/// Number of rounds of the Feistel network permuting the ranks.
+const ROUNDS: usize = 4;
+
+/// A non-standard iterator yielding every combination of elements from a sequence exactly once,
+/// in a pseudo-random order determined by a seed.
+///
+/// Created by `shuffled_combinations`.
+pub struct ShuffledCombinator<'a, T>
+    where T: 'a
+{
+    seq: &'a [T],
+    indices: Vec<usize>,
+    permutation: RankPermutation,
+    position: u128,
+}
+
+impl<'a, 'b, T> ShuffledCombinator<'a, T> {
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&'b mut self) -> Option<CombinationIter<'a, 'b, T>> {
+        if self.position == self.permutation.total {
+            return None;
+        }
+
+        let rank = self.permutation.apply(self.position);
+        rank::unrank_into(rank, self.seq.len(), &mut self.indices);
+        self.position += 1;
+
+        Some(CombinationIter {
+            it: self.indices.iter(),
+            seq: self.seq,
+        })
+    }
+}
+
+impl<'a, T> ShuffledCombinator<'a, T> {
+    /// Returns the indices into the sequence of the combination most recently produced by `next`.
+    pub fn indices(&self) -> &[usize] {
+        &self.indices
+    }
+
+    /// Returns the number of combinations still to be produced by `next`.
+    pub fn remaining(&self) -> u128 {
+        self.permutation.total - self.position
+    }
+}
+
+impl<'a, 'b, T> LendingItem<'b> for ShuffledCombinator<'a, T> {
+    type Item = CombinationIter<'a, 'b, T>;
+}
+
+impl<'a, T> LendingIterator for ShuffledCombinator<'a, T> {
+    fn next(&mut self) -> Option<CombinationIter<'a, '_, T>> {
+        ShuffledCombinator::next(self)
+    }
+}
+
+/// A keyed bijection on `0..total`, built from a balanced Feistel network over the smallest
+/// power of four covering `total`, restricted to the range by cycle walking.
+struct RankPermutation {
+    total: u128,
+    half_bits: u32,
+    keys: [u64; ROUNDS],
+}
+
+impl RankPermutation {
+    fn new(total: u128, seed: u64) -> RankPermutation {
+        let bits = if total > 1 { 128 - (total - 1).leading_zeros() } else { 0 };
+
+        let mut state = seed;
+        let mut keys = [0; ROUNDS];
+        for key in keys.iter_mut() {
+            state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+            *key = mix(state);
+        }
+
+        RankPermutation {
+            total,
+            half_bits: bits.div_ceil(2),
+            keys,
+        }
+    }
+
+    fn apply(&self, rank: u128) -> u128 {
+        // The network permutes a domain at most four times larger than the range, so outputs
+        // outside it are fed back in until one lands inside, taking few steps on average
+        let mut rank = self.feistel(rank);
+        while rank >= self.total {
+            rank = self.feistel(rank);
+        }
+        rank
+    }
+
+    fn feistel(&self, value: u128) -> u128 {
+        let mask = (1u128 << self.half_bits) - 1;
+        let mut left = value >> self.half_bits;
+        let mut right = value & mask;
+
+        for &key in self.keys.iter() {
+            let round = mix(right as u64 ^ key) as u128 & mask;
+            let next = left ^ round;
+            left = right;
+            right = next;
+        }
+
+        (left << self.half_bits) | right
+    }
+}
+
+/// The SplitMix64 finaliser, a fast bijective mixing function on 64-bit words.
+fn mix(mut x: u64) -> u64 {
+    x = (x ^ (x >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    x = (x ^ (x >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    x ^ (x >> 31)
+}
+
+/// Returns a (non-standard) iterator yielding every combination of length `len` from the sequence
+/// `seq` exactly once, in a pseudo-random order determined by `seed`.
+///
+/// Rather than storing the combinations, the iterator permutes their ranks with a keyed
+/// bijection and unranks each in turn, so memory use is independent of the number of
+/// combinations. The order is suitable for spreading early results evenly, but is not
+/// cryptographically secure or exactly uniform over all orderings.
+///
+/// # Panics
+/// When attempting to iterate over combination lengths longer than the original sequence, or
+/// when the number of combinations does not fit in a `u128`.
+///
+/// # Examples
+///
+/// ```
+/// use combo::shuffled_combinations;
+///
+/// let sequence = ['a', 'b', 'c', 'd'];
+/// let mut combinator = shuffled_combinations(&sequence[..], 2, 42);
+///
+/// let mut combinations = Vec::new();
+/// while let Some(combo) = combinator.next() {
+///     combinations.push(combo.collect::<String>());
+/// }
+///
+/// combinations.sort();
+/// assert_eq!(combinations, vec!["ab", "ac", "ad", "bc", "bd", "cd"]);
+/// ```
+pub fn shuffled_combinations<T>(seq: &[T], len: usize, seed: u64) -> ShuffledCombinator<'_, T> {
+    if let Err(err) = error::check_len(len, seq.len()) {
+        panic!("{}", err);
+    }
+
+    let total = count::binomial(seq.len(), len).expect("Number of combinations overflows u128");
+
+    ShuffledCombinator {
+        seq,
+        indices: (0..len).collect(),
+        permutation: RankPermutation::new(total, seed),
+        position: 0,
+    }
+}
+
+#[test]
+fn every_combination_produced_once() {
+    let sequence: Vec<u32> = (0..9).collect();
+
+    for len in 0..10 {
+        for seed in 0..4 {
+            let mut combinator = shuffled_combinations(&sequence[..], len, seed);
+            let mut ranks = Vec::new();
+            while combinator.next().is_some() {
+                ranks.push(rank::rank_combination(combinator.indices(), 9));
+            }
+
+            let unshuffled = ranks.windows(2).all(|pair| pair[0] < pair[1]);
+            assert!(ranks.len() < 10 || !unshuffled);
+
+            ranks.sort();
+            let total = count::binomial(9, len).unwrap();
+            assert_eq!(ranks, (0..total).collect::<Vec<_>>());
+        }
+    }
+}
+
+#[test]
+fn permutation_handles_largest_ranges() {
+    let permutation = RankPermutation::new(u128::MAX, 1);
+    assert_eq!(permutation.half_bits, 64);
+
+    let ranks: Vec<u128> = (0..16).map(|rank| permutation.apply(rank)).collect();
+    let mut sorted = ranks.clone();
+    sorted.sort();
+    sorted.dedup();
+    assert_eq!(sorted.len(), ranks.len());
+    assert!(ranks.iter().all(|&rank| rank < u128::MAX));
+}
@@ -0,0 +1,215 @@
+//! Splitting a `Combinator` into independent combinators over contiguous ranges of ranks.
+
+use count;
+use Combinator;
+
+impl<'a, T> Combinator<'a, T> {
+    /// Returns the rank of the combination the next call to `next` would produce, ignoring any
+    /// bound from a split.
+    pub(crate) fn position(&self) -> u128 {
+        self.total() - count::remaining(&self.indices, self.seq.len(), self.inited).unwrap()
+    }
+
+    /// Returns the rank one past the last combination this combinator will produce.
+    pub(crate) fn end(&self) -> u128 {
+        match self.left {
+            Some(left) => self.position() + left,
+            None => self.total(),
+        }
+    }
+
+    fn total(&self) -> u128 {
+        count::binomial(self.seq.len(), self.indices.len())
+            .expect("Number of combinations overflows u128")
+    }
+
+    /// Splits the remaining combinations into two independent combinators, the first producing
+    /// those ranked before `rank` and the second those from `rank` onwards.
+    ///
+    /// # Panics
+    /// When `rank` lies outside the range of combinations remaining, or the total number of
+    /// combinations does not fit in a `u128`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use combo::combinations;
+    ///
+    /// let sequence: Vec<u32> = (0..5).collect();
+    /// let (mut head, tail) = combinations(&sequence[..], 3).split_at(4);
+    ///
+    /// assert_eq!(head.remaining(), Some(4));
+    /// assert_eq!(tail.owned().next(), Some(vec![&0, &2, &4]));
+    /// ```
+    pub fn split_at(self, rank: u128) -> (Self, Self) {
+        let start = self.position();
+        let end = self.end();
+        if rank < start || rank > end {
+            panic!("Split rank out of range ({} not in {}..={})", rank, start, end);
+        }
+
+        let mut tail = Combinator {
+            seq: self.seq,
+            indices: self.indices.clone(),
+            inited: self.inited,
+            changed: self.changed,
+            left: None,
+            start: rank,
+        };
+        if rank < self.total() {
+            tail.seek(rank);
+        } else {
+            // No combination has this rank, so park the cursor just past the last one
+            tail.seek(rank - 1);
+            tail.inited = true;
+        }
+        tail.left = Some(end - rank);
+
+        let head = Combinator {
+            left: Some(rank - start),
+            start,
+            ..self
+        };
+
+        (head, tail)
+    }
+
+    /// Splits the remaining combinations into `n` independent combinators over contiguous ranges
+    /// of near-equal length, in order.
+    ///
+    /// # Panics
+    /// When `n` is zero, or the total number of combinations does not fit in a `u128`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use combo::combinations;
+    ///
+    /// let sequence: Vec<u32> = (0..5).collect();
+    /// let chunks = combinations(&sequence[..], 3).chunks(3);
+    ///
+    /// let lengths: Vec<u128> = chunks.iter().map(|c| c.remaining().unwrap()).collect();
+    /// assert_eq!(lengths, vec![4, 3, 3]);
+    /// ```
+    pub fn chunks(self, n: usize) -> Vec<Self> {
+        if n == 0 {
+            panic!("Cannot split combinations into zero chunks");
+        }
+
+        let start = self.position();
+        let len = self.end() - start;
+        let (size, extra) = (len / n as u128, len % n as u128);
+
+        let mut chunks = Vec::with_capacity(n);
+        let mut rest = self;
+        let mut split = start;
+        for i in 1..n as u128 {
+            split += size + if i <= extra { 1 } else { 0 };
+            let (head, tail) = rest.split_at(split);
+            chunks.push(head);
+            rest = tail;
+        }
+        chunks.push(rest);
+
+        chunks
+    }
+}
+
+#[cfg(test)]
+use combinations;
+
+#[test]
+fn chunks_cover_all_combinations_in_order() {
+    let sequence: Vec<u32> = (0..9).collect();
+
+    let all: Vec<Vec<&u32>> = combinations(&sequence[..], 4).owned().collect();
+
+    for n in 1..10 {
+        let mut combinator = combinations(&sequence[..], 4);
+        combinator.seek(5);
+
+        let chunked: Vec<Vec<&u32>> = combinator.chunks(n)
+                                                .into_iter()
+                                                .flat_map(|chunk| chunk.owned())
+                                                .collect();
+        assert_eq!(&all[5..], &chunked[..]);
+    }
+}
+
+#[test]
+fn nested_splits_respect_bounds() {
+    let sequence: Vec<u32> = (0..6).collect();
+
+    let (head, _) = combinations(&sequence[..], 3).split_at(12);
+    let (first, second) = head.split_at(5);
+    assert_eq!(first.owned().count(), 5);
+
+    let mut second = second;
+    second.seek(10);
+    assert_eq!(second.remaining(), Some(2));
+    assert_eq!(second.owned().count(), 2);
+}
+
+#[test]
+fn chunk_steps_backwards_within_range() {
+    let sequence: Vec<u32> = (0..6).collect();
+
+    let all: Vec<Vec<u32>> = combinations(&sequence[..], 3).cloned().collect();
+    let mut middle = combinations(&sequence[..], 3).chunks(4).swap_remove(1);
+    middle.seek_last();
+
+    let mut reversed = Vec::new();
+    while let Some(combo) = middle.prev() {
+        reversed.push(combo.cloned().collect::<Vec<u32>>());
+    }
+    reversed.reverse();
+    assert_eq!(&reversed[..], &all[5..10]);
+
+    assert_eq!(middle.remaining(), Some(4));
+    assert_eq!(middle.cloned().collect::<Vec<_>>(), &all[6..10]);
+}
+
+#[test]
+fn more_chunks_than_combinations() {
+    let sequence: Vec<u32> = (0..5).collect();
+
+    let chunks = combinations(&sequence[..], 3).chunks(12);
+    let lengths: Vec<u128> = chunks.iter().map(|c| c.remaining().unwrap()).collect();
+    assert_eq!(lengths, vec![1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 0, 0]);
+
+    let all: Vec<Vec<&u32>> = combinations(&sequence[..], 3).owned().collect();
+    let chunked: Vec<Vec<&u32>> = chunks.into_iter().flat_map(|chunk| chunk.owned()).collect();
+    assert_eq!(chunked, all);
+}
+
+#[test]
+fn first_chunk_of_seeked_combinator_steps_backwards_within_range() {
+    let sequence: Vec<u32> = (0..6).collect();
+
+    let mut combinator = combinations(&sequence[..], 3);
+    combinator.seek(5);
+    let mut first = combinator.chunks(2).remove(0);
+    first.seek_last();
+
+    let mut ranks = Vec::new();
+    while first.prev().is_some() {
+        ranks.push(first.rank().unwrap());
+    }
+    assert_eq!(ranks, vec![12, 11, 10, 9, 8, 7, 6, 5]);
+}
+
+#[test]
+fn empty_ranges_stay_empty_after_seek_last() {
+    let sequence: Vec<u32> = (0..5).collect();
+
+    let (_, mut last) = combinations(&sequence[..], 3).split_at(10);
+    let (_, rest) = combinations(&sequence[..], 3).split_at(4);
+    let (mut middle, _) = rest.split_at(4);
+
+    for empty in [&mut last, &mut middle] {
+        empty.seek_last();
+        assert_eq!(empty.remaining(), Some(0));
+        assert!(empty.prev().is_none());
+        assert!(empty.next().is_none());
+    }
+}
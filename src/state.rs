@@ -0,0 +1,160 @@
+//! Saving and restoring the position of a `Combinator`, enabled by the `serde` feature.
+
+use serde::{Deserialize, Serialize};
+
+use count;
+use {ComboError, Combinator};
+
+/// A serializable snapshot of the position of a `Combinator`, independent of its sequence.
+///
+/// Created by `Combinator::state`, and resumed with `Combinator::resume`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CombinatorState {
+    /// The length of the sequence.
+    pub n: usize,
+    /// The length of each combination.
+    pub k: usize,
+    /// The cursor indices into the sequence.
+    pub indices: Vec<usize>,
+    /// Whether `indices` has already been produced, rather than being the next to produce.
+    pub inited: bool,
+    /// Number of combinations still to be produced, when bounded by a split.
+    pub left: Option<u128>,
+    /// Rank of the first combination in the range, when bounded by a split.
+    pub start: u128,
+}
+
+impl<'a, T> Combinator<'a, T> {
+    /// Returns a snapshot of the position of the combinator, from which enumeration can later be
+    /// continued with `resume`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use combo::{combinations, Combinator};
+    ///
+    /// let sequence: Vec<u32> = (0..5).collect();
+    /// let mut combinator = combinations(&sequence[..], 3);
+    /// combinator.next();
+    /// combinator.next();
+    ///
+    /// let state = combinator.state();
+    /// let mut resumed = Combinator::resume(&sequence[..], state).unwrap();
+    ///
+    /// assert_eq!(resumed.next().unwrap().collect::<Vec<_>>(), vec![&0, &1, &4]);
+    /// ```
+    pub fn state(&self) -> CombinatorState {
+        CombinatorState {
+            n: self.seq.len(),
+            k: self.indices.len(),
+            indices: self.indices.clone(),
+            inited: self.inited,
+            left: self.left,
+            start: self.start,
+        }
+    }
+
+    /// Returns a combinator over `seq` continuing from a snapshot taken with `state`, or an error
+    /// if the snapshot is inconsistent or was taken against a sequence of a different length.
+    pub fn resume(seq: &'a [T], state: CombinatorState) -> Result<Combinator<'a, T>, ComboError> {
+        let invalid = |reason| Err(ComboError::InvalidState { reason });
+
+        if state.n != seq.len() {
+            return invalid("sequence length differs");
+        }
+        if state.k != state.indices.len() {
+            return invalid("number of indices differs from combination length");
+        }
+        if state.indices.windows(2).any(|pair| pair[0] >= pair[1]) {
+            return invalid("indices not strictly increasing");
+        }
+        if state.indices.last().is_some_and(|&index| index >= state.n) {
+            return invalid("index out of range");
+        }
+        if let Some(left) = state.left {
+            // Only combinators with a countable total can be split
+            let total = match count::binomial(state.n, state.k) {
+                Some(total) => total,
+                None => return invalid("bound on more combinations than fit in a u128"),
+            };
+            let remaining = count::remaining(&state.indices, state.n, state.inited).unwrap();
+            if left > remaining {
+                return invalid("bound exceeds remaining combinations");
+            }
+            if state.start > total - remaining {
+                return invalid("range starts after current position");
+            }
+        } else if state.start != 0 {
+            return invalid("range start without bound");
+        }
+
+        Ok(Combinator {
+            seq,
+            indices: state.indices,
+            inited: state.inited,
+            changed: 0,
+            left: state.left,
+            start: state.start,
+        })
+    }
+}
+
+#[cfg(test)]
+use combinations;
+
+#[test]
+fn resumed_combinator_continues_enumeration() {
+    let sequence: Vec<u32> = (0..6).collect();
+
+    let mut combinator = combinations(&sequence[..], 3);
+    for _ in 0..7 {
+        combinator.next();
+    }
+
+    let json = serde_json::to_string(&combinator.state()).unwrap();
+    let state: CombinatorState = serde_json::from_str(&json).unwrap();
+    let resumed = Combinator::resume(&sequence[..], state).unwrap();
+
+    let expected: Vec<Vec<&u32>> = combinator.owned().collect();
+    assert_eq!(resumed.owned().collect::<Vec<_>>(), expected);
+}
+
+#[test]
+fn resume_rejects_inconsistent_states() {
+    let sequence: Vec<u32> = (0..6).collect();
+    let state = combinations(&sequence[..], 3).state();
+
+    let invalid = |state| Combinator::resume(&sequence[..], state).err();
+
+    assert!(invalid(CombinatorState { n: 5, ..state.clone() }).is_some());
+    assert!(invalid(CombinatorState { k: 2, ..state.clone() }).is_some());
+    assert!(invalid(CombinatorState { indices: vec![0, 2, 2], ..state.clone() }).is_some());
+    assert!(invalid(CombinatorState { indices: vec![0, 2, 6], ..state.clone() }).is_some());
+    assert!(invalid(CombinatorState { left: Some(21), ..state.clone() }).is_some());
+    assert!(invalid(CombinatorState { start: 1, ..state.clone() }).is_some());
+    assert!(invalid(CombinatorState { left: Some(20), start: 1, ..state.clone() }).is_some());
+    assert_eq!(invalid(CombinatorState { left: Some(20), ..state }), None);
+}
+
+#[test]
+fn split_states_round_trip() {
+    let sequence: Vec<u32> = (0..5).collect();
+
+    let mut advanced = combinations(&sequence[..], 3);
+    advanced.next();
+    advanced.next();
+    let (head, tail) = advanced.split_at(4);
+
+    let mut combinators = combinations(&sequence[..], 3).chunks(11);
+    let (whole, empty) = combinations(&sequence[..], 3).split_at(10);
+    combinators.extend(vec![head, tail, whole, empty]);
+
+    for combinator in combinators {
+        let json = serde_json::to_string(&combinator.state()).unwrap();
+        let state: CombinatorState = serde_json::from_str(&json).unwrap();
+        let resumed = Combinator::resume(&sequence[..], state).unwrap();
+
+        assert_eq!(resumed.state(), combinator.state());
+        assert_eq!(resumed.owned().collect::<Vec<_>>(), combinator.owned().collect::<Vec<_>>());
+    }
+}
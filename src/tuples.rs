@@ -0,0 +1,152 @@
+//! Fast paths for pairs and triples of elements, yielded as tuples.
+
+use count;
+
+/// An `Iterator` yielding every pair of elements from a sequence.
+///
+/// Created by `pairs`.
+pub struct Pairs<'a, T>
+    where T: 'a
+{
+    seq: &'a [T],
+    i: usize,
+    j: usize,
+}
+
+impl<'a, T> Iterator for Pairs<'a, T> {
+    type Item = (&'a T, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let n = self.seq.len();
+
+        // Carry into the first counter once the second reaches the end
+        if self.j >= n {
+            if self.i + 2 >= n {
+                return None;
+            }
+            self.i += 1;
+            self.j = self.i + 1;
+        }
+
+        let pair = (&self.seq[self.i], &self.seq[self.j]);
+        self.j += 1;
+        Some(pair)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = self.seq.len();
+        let remaining = count::binomial(n.saturating_sub(self.i + 1), 2)
+                            .map(|rest| rest + n.saturating_sub(self.j) as u128);
+        count::size_hint(remaining)
+    }
+}
+
+/// An `Iterator` yielding every triple of elements from a sequence.
+///
+/// Created by `triples`.
+pub struct Triples<'a, T>
+    where T: 'a
+{
+    seq: &'a [T],
+    i: usize,
+    j: usize,
+    k: usize,
+}
+
+impl<'a, T> Iterator for Triples<'a, T> {
+    type Item = (&'a T, &'a T, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let n = self.seq.len();
+
+        // Carry into the outer counters once the innermost reaches the end
+        if self.k >= n {
+            if self.j + 2 < n {
+                self.j += 1;
+            } else if self.i + 3 < n {
+                self.i += 1;
+                self.j = self.i + 1;
+            } else {
+                return None;
+            }
+            self.k = self.j + 1;
+        }
+
+        let triple = (&self.seq[self.i], &self.seq[self.j], &self.seq[self.k]);
+        self.k += 1;
+        Some(triple)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = self.seq.len();
+        let later_i = count::binomial(n.saturating_sub(self.i + 1), 3);
+        let later_j = count::binomial(n.saturating_sub(self.j + 1), 2);
+        let remaining = later_i.and_then(|later_i| later_j.map(|later_j| later_i + later_j))
+                               .map(|later| later + n.saturating_sub(self.k) as u128);
+        count::size_hint(remaining)
+    }
+}
+
+/// Returns an iterator yielding every pair of elements from the sequence `seq`, in the same order
+/// as `combinations(seq, 2)`.
+///
+/// # Examples
+///
+/// ```
+/// use combo::pairs;
+///
+/// let sequence = [1, 2, 3];
+/// let products: Vec<i32> = pairs(&sequence).map(|(a, b)| a * b).collect();
+///
+/// assert_eq!(products, vec![2, 3, 6]);
+/// ```
+pub fn pairs<T>(seq: &[T]) -> Pairs<'_, T> {
+    Pairs { seq, i: 0, j: 1 }
+}
+
+/// Returns an iterator yielding every triple of elements from the sequence `seq`, in the same
+/// order as `combinations(seq, 3)`.
+pub fn triples<T>(seq: &[T]) -> Triples<'_, T> {
+    Triples {
+        seq,
+        i: 0,
+        j: 1,
+        k: 2,
+    }
+}
+
+#[cfg(test)]
+use combinations;
+
+#[test]
+fn tuples_match_combinations() {
+    for n in 0..8 {
+        let sequence: Vec<usize> = (0..n).collect();
+
+        let mut iter = pairs(&sequence);
+        let mut expected = if n >= 2 {
+            combinations(&sequence[..], 2).owned().collect()
+        } else {
+            Vec::new()
+        };
+        assert_eq!(iter.size_hint(), (expected.len(), Some(expected.len())));
+        while let Some((a, b)) = iter.next() {
+            assert_eq!(vec![a, b], expected.remove(0));
+            assert_eq!(iter.size_hint(), (expected.len(), Some(expected.len())));
+        }
+        assert!(iter.next().is_none());
+
+        let mut iter = triples(&sequence);
+        let mut expected = if n >= 3 {
+            combinations(&sequence[..], 3).owned().collect()
+        } else {
+            Vec::new()
+        };
+        assert_eq!(iter.size_hint(), (expected.len(), Some(expected.len())));
+        while let Some((a, b, c)) = iter.next() {
+            assert_eq!(vec![a, b, c], expected.remove(0));
+            assert_eq!(iter.size_hint(), (expected.len(), Some(expected.len())));
+        }
+        assert!(iter.next().is_none());
+    }
+}